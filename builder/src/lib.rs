@@ -1,260 +1,2615 @@
-use core::panic;
-
-use proc_macro::{Span, TokenStream};
+use proc_macro::TokenStream;
 use quote::{format_ident, quote, ToTokens};
+use syn::ext::IdentExt;
+use syn::visit_mut::VisitMut;
 use syn::{
-    parse_macro_input, parse_quote, spanned::Spanned, AngleBracketedGenericArguments, Attribute, Data, DataStruct, DeriveInput, Expr, ExprLit, Field, GenericArgument, Lit, Meta, Path, PathArguments, PathSegment, Type, TypePath
+    parse_macro_input, parse_quote, punctuated::Punctuated, AngleBracketedGenericArguments,
+    Attribute, Data, DataStruct, DeriveInput, Expr, Field, FieldMutability, Fields,
+    GenericArgument, GenericParam, Ident, Index, Item, LitStr, Member, Meta, Path, PathArguments,
+    PathSegment, Token, Type, TypePath, Visibility,
 };
 
-// If there is an "#[builder(each = "...")] specified, return the name.
-fn match_vec_each(attrs: &Vec<Attribute>) -> Result<Option<String>, syn::Error> {
-    if attrs.is_empty() {
-        return Ok(None);
-    }
-    let attr = &attrs[0];
+// Options parsed from a field's `#[builder(...)]` attribute.
+#[derive(Default)]
+struct FieldAttrs {
+    // `#[builder(each = "...")]`: name of the one-at-a-time setter.
+    each: Option<LitStr>,
+    // `#[builder(skip)]`: no setter, `build()` uses `Default::default()`.
+    skip: bool,
+    // `#[builder(into)]`: the setter accepts `impl Into<T>`.
+    into: bool,
+    // `#[builder(default = "...")]`: used by `build()` if the field is unset.
+    // `#[builder(default_fn = "...")]` is the same with a call to the named
+    // function.
+    default: Option<Expr>,
+    // `#[builder(rename = "...")]`: name of the all-at-once setter.
+    rename: Option<Ident>,
+    // `#[builder(nested)]`: the field's type derives `Builder` too, and the
+    // setter configures it through a closure.
+    nested: bool,
+    // `#[builder(try_into)]`: the setter accepts `impl TryInto<T>` and
+    // returns the conversion error.
+    try_into: bool,
+    // `#[builder(unwrap)]`: a `Box`, `Rc` or `Arc` field, optionally inside
+    // an `Option`, whose setter takes the inner value, wrapped by `build()`.
+    unwrap: bool,
+    // `#[builder(collection = "...")]`: treat a field whose type is hidden
+    // behind an alias as a `"vec"`, `"vec_deque"` or `"hash_set"`.
+    collection: Option<String>,
+    // `#[builder(required)]`: an `Option` field that must be set anyway,
+    // with the setter taking the whole `Option`.
+    required: bool,
+    // `#[builder(extend)]`: a collection of any `Default + Extend` type. The
+    // `each` setter takes whatever the collection can be extended with.
+    extend: bool,
+    // `#[builder(validate = "...")]`: a function the setter passes the new
+    // value to, and which can reject it with an error.
+    validate: Option<Path>,
+    // `#[builder(set_once)]`: the setter refuses to overwrite a value that
+    // was already set, by panicking or, with `set_once = "error"`, by
+    // returning an error.
+    set_once: Option<SetOnce>,
+    // `#[builder(opt_setter)]`: also a `..._opt()` setter that takes an
+    // `Option` and leaves the field alone when given `None`.
+    opt_setter: bool,
+}
 
-    let err = |span| Err(syn::Error::new(
-        span,
-        "expected `builder(each = \"...\")`",
-    ));
+// What a `set_once` setter does when the field is already set.
+#[derive(Clone, Copy, PartialEq)]
+enum SetOnce {
+    Panic,
+    Error,
+}
 
-    if attrs.len() != 1 {
-        return err(attr.span());
+// Parse and merge all `#[builder(...)]` attributes on a field. Attributes
+// outside the `builder` namespace, like doc comments, are ignored, and so is
+// a bare `#[builder]` without any options.
+fn parse_field_attrs(attrs: &[Attribute]) -> Result<FieldAttrs, syn::Error> {
+    let mut field_attrs = FieldAttrs::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("builder")) {
+        if let Meta::Path(_) = attr.meta {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            let duplicate = || meta.error("duplicate builder option");
+            if meta.path.is_ident("each") {
+                let lit: LitStr = meta.value()?.parse()?;
+                // Checked here so the setter name can't fail to parse later.
+                parse_ident(&lit)?;
+                if field_attrs.each.replace(lit).is_some() {
+                    return Err(duplicate());
+                }
+            } else if meta.path.is_ident("default") {
+                let lit: LitStr = meta.value()?.parse()?;
+                if field_attrs.default.replace(lit.parse()?).is_some() {
+                    return Err(duplicate());
+                }
+            } else if meta.path.is_ident("default_fn") {
+                let lit: LitStr = meta.value()?.parse()?;
+                let function: Path = lit.parse()?;
+                if field_attrs
+                    .default
+                    .replace(parse_quote! { #function() })
+                    .is_some()
+                {
+                    return Err(duplicate());
+                }
+            } else if meta.path.is_ident("rename") {
+                let lit: LitStr = meta.value()?.parse()?;
+                if field_attrs.rename.replace(parse_ident(&lit)?).is_some() {
+                    return Err(duplicate());
+                }
+            } else if meta.path.is_ident("skip") {
+                if std::mem::replace(&mut field_attrs.skip, true) {
+                    return Err(duplicate());
+                }
+            } else if meta.path.is_ident("into") {
+                if std::mem::replace(&mut field_attrs.into, true) {
+                    return Err(duplicate());
+                }
+            } else if meta.path.is_ident("nested") {
+                if std::mem::replace(&mut field_attrs.nested, true) {
+                    return Err(duplicate());
+                }
+            } else if meta.path.is_ident("try_into") {
+                if std::mem::replace(&mut field_attrs.try_into, true) {
+                    return Err(duplicate());
+                }
+            } else if meta.path.is_ident("collection") {
+                let lit: LitStr = meta.value()?.parse()?;
+                if !["vec", "vec_deque", "hash_set"].contains(&lit.value().as_str()) {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        "expected `collection = \"vec\"`, `\"vec_deque\"` or `\"hash_set\"`",
+                    ));
+                }
+                if field_attrs.collection.replace(lit.value()).is_some() {
+                    return Err(duplicate());
+                }
+            } else if meta.path.is_ident("unwrap") {
+                if std::mem::replace(&mut field_attrs.unwrap, true) {
+                    return Err(duplicate());
+                }
+            } else if meta.path.is_ident("extend") {
+                if std::mem::replace(&mut field_attrs.extend, true) {
+                    return Err(duplicate());
+                }
+            } else if meta.path.is_ident("validate") {
+                let lit: LitStr = meta.value()?.parse()?;
+                if field_attrs.validate.replace(lit.parse()?).is_some() {
+                    return Err(duplicate());
+                }
+            } else if meta.path.is_ident("required") {
+                if std::mem::replace(&mut field_attrs.required, true) {
+                    return Err(duplicate());
+                }
+            } else if meta.path.is_ident("opt_setter") {
+                if std::mem::replace(&mut field_attrs.opt_setter, true) {
+                    return Err(duplicate());
+                }
+            } else if meta.path.is_ident("set_once") {
+                let set_once = if meta.input.peek(Token![=]) {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    match lit.value().as_str() {
+                        "panic" => SetOnce::Panic,
+                        "error" => SetOnce::Error,
+                        _ => {
+                            return Err(syn::Error::new(
+                                lit.span(),
+                                "expected `set_once = \"panic\"` or `\"error\"`",
+                            ))
+                        }
+                    }
+                } else {
+                    SetOnce::Panic
+                };
+                if field_attrs.set_once.replace(set_once).is_some() {
+                    return Err(duplicate());
+                }
+            } else {
+                return Err(meta.error("expected `builder(each = \"...\")`"));
+            }
+            if field_attrs.skip
+                && (field_attrs.each.is_some()
+                    || field_attrs.into
+                    || field_attrs.default.is_some()
+                    || field_attrs.rename.is_some()
+                    || field_attrs.nested
+                    || field_attrs.try_into
+                    || field_attrs.unwrap
+                    || field_attrs.collection.is_some()
+                    || field_attrs.required
+                    || field_attrs.extend
+                    || field_attrs.validate.is_some()
+                    || field_attrs.set_once.is_some()
+                    || field_attrs.opt_setter)
+            {
+                return Err(meta.error("`skip` cannot be combined with other builder options"));
+            }
+            if field_attrs.nested && field_attrs.into {
+                return Err(meta.error("`nested` cannot be combined with `into`"));
+            }
+            if field_attrs.required && (field_attrs.default.is_some() || field_attrs.unwrap) {
+                return Err(meta.error("`required` cannot be combined with `default` or `unwrap`"));
+            }
+            if field_attrs.extend
+                && (field_attrs.collection.is_some() || field_attrs.unwrap || field_attrs.required)
+            {
+                return Err(meta.error(
+                    "`extend` cannot be combined with `collection`, `unwrap` or `required`",
+                ));
+            }
+            // Only the all-at-once setter has a value to validate, and one
+            // that it can return an error from.
+            if field_attrs.validate.is_some()
+                && (field_attrs.each.is_some() || field_attrs.nested || field_attrs.try_into)
+            {
+                return Err(
+                    meta.error("`validate` cannot be combined with `each`, `nested` or `try_into`")
+                );
+            }
+            // `each` setters add to the field rather than overwrite it, and
+            // `try_into` already returns the conversion's own error type.
+            if field_attrs.set_once.is_some() && field_attrs.each.is_some() {
+                return Err(meta.error("`set_once` cannot be combined with `each`"));
+            }
+            if field_attrs.set_once == Some(SetOnce::Error) && field_attrs.try_into {
+                return Err(meta.error("`set_once = \"error\"` cannot be combined with `try_into`"));
+            }
+            // The `..._opt()` setter returns the builder, like the setter it
+            // forwards to has to.
+            if field_attrs.opt_setter
+                && (field_attrs.nested
+                    || field_attrs.try_into
+                    || field_attrs.validate.is_some()
+                    || field_attrs.set_once == Some(SetOnce::Error))
+            {
+                return Err(meta
+                    .error("`opt_setter` cannot be combined with setters that return a `Result`"));
+            }
+            if field_attrs.try_into && (field_attrs.into || field_attrs.nested) {
+                return Err(meta.error("`try_into` cannot be combined with `into` or `nested`"));
+            }
+            Ok(())
+        })?;
     }
+    Ok(field_attrs)
+}
+
+// Options parsed from the `#[builder(...)]` attributes on the struct itself.
+#[derive(Default)]
+struct StructAttrs {
+    // `#[builder(name = "...")]`: name of the generated builder struct.
+    name: Option<Ident>,
+    // `#[builder(build_name = "...")]`: name of the method that builds the
+    // struct, `build` by default.
+    build_name: Option<Ident>,
+    // `#[builder(constructor = "...")]`: name of the associated function on
+    // the struct that creates the builder, `builder` by default.
+    constructor: Option<Ident>,
+    // `#[builder(error = "boxed")]`: `build()` returns `Box<dyn Error>`
+    // instead of the generated error enum.
+    boxed_error: bool,
+    // `#[builder(cfg = "...")]`: a condition gating everything generated,
+    // for example on a cargo feature.
+    cfg: Option<Meta>,
+    // `#[builder(derive(...))]`: traits to derive on the builder struct.
+    derives: Vec<Path>,
+    // `#[builder(build_by = "value")]`: `build()` consumes the builder.
+    build_by_value: bool,
+    // `#[builder(style = "owned")]`: setters take and return the builder by
+    // value. Implies `build_by = "value"`.
+    owned: bool,
+    // `#[builder(vis = "...")]`: visibility of the generated methods.
+    vis: Option<Visibility>,
+    // `#[builder(must_use)]`: warn when `builder()` or a setter's returned
+    // builder is discarded.
+    must_use: bool,
+    // `#[builder(inline)]`: mark `builder()` and the setters `#[inline]`.
+    inline: bool,
+    // `#[builder(validate = "...")]`: a function `build()` passes the
+    // assembled struct to, which can reject it with an error.
+    validate: Option<Path>,
+    // `#[builder(typestate)]`: the builder's type tracks which required
+    // fields are set, and `build()` only exists once all of them are.
+    // Implies `style = "owned"`.
+    typestate: bool,
+    // `#[builder(default)]`: `build()` can't fail. It starts from the
+    // struct's `Default` and overrides the fields that were set.
+    default: bool,
+    // `#[builder(unchecked)]`: also generate an `unsafe fn build_unchecked()`
+    // that assumes every required field is set instead of checking.
+    unchecked: bool,
+    // `#[builder(clone_build)]`: also generate a `build_clone()` that builds
+    // from clones of the fields, leaving the builder intact.
+    clone_build: bool,
+    // `#[builder(boxed)]`: also generate a `build_boxed()` that returns the
+    // built struct in a `Box`.
+    boxed: bool,
+    // `#[builder(merge = "...")]`: which value `merge()` keeps for a field
+    // set in both builders. Unless given, a field already set keeps its own.
+    merge: Option<Merge>,
+    // `#[builder(const)]`: `builder()` is a `const fn`.
+    const_constructor: bool,
+    // `#[builder(clearable)]`: a `clear_...()` method per field that unsets
+    // it again.
+    clearable: bool,
+}
+
+// Which value `merge()` keeps for a field both builders have set.
+#[derive(Clone, Copy, PartialEq)]
+enum Merge {
+    Fill,
+    Override,
+}
+
+// Parse a string literal that is supposed to hold an identifier.
+fn parse_ident(lit: &LitStr) -> Result<Ident, syn::Error> {
+    lit.parse()
+        .map_err(|_| syn::Error::new(lit.span(), "expected a valid identifier"))
+}
+
+// Options that only mean something on a field, as opposed to options that are
+// merely unknown.
+const FIELD_ONLY_OPTIONS: &[&str] = &[
+    "each",
+    "default_fn",
+    "skip",
+    "into",
+    "rename",
+    "nested",
+    "try_into",
+    "unwrap",
+    "collection",
+    "required",
+    "extend",
+    "set_once",
+    "opt_setter",
+];
 
-    if !attr.path().is_ident("builder") {
-        return err(attr.path().span());
+fn parse_struct_attrs(attrs: &[Attribute]) -> Result<StructAttrs, syn::Error> {
+    let mut struct_attrs = StructAttrs::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("builder")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                struct_attrs.name = Some(parse_ident(&meta.value()?.parse()?)?);
+            } else if meta.path.is_ident("build_name") {
+                struct_attrs.build_name = Some(parse_ident(&meta.value()?.parse()?)?);
+            } else if meta.path.is_ident("constructor") {
+                struct_attrs.constructor = Some(parse_ident(&meta.value()?.parse()?)?);
+            } else if meta.path.is_ident("error") {
+                let lit: LitStr = meta.value()?.parse()?;
+                if lit.value() != "boxed" {
+                    return Err(syn::Error::new(lit.span(), "expected `error = \"boxed\"`"));
+                }
+                struct_attrs.boxed_error = true;
+            } else if meta.path.is_ident("build_by") {
+                let lit: LitStr = meta.value()?.parse()?;
+                if lit.value() != "value" {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        "expected `build_by = \"value\"`",
+                    ));
+                }
+                struct_attrs.build_by_value = true;
+            } else if meta.path.is_ident("style") {
+                let lit: LitStr = meta.value()?.parse()?;
+                if lit.value() != "owned" {
+                    return Err(syn::Error::new(lit.span(), "expected `style = \"owned\"`"));
+                }
+                struct_attrs.owned = true;
+                struct_attrs.build_by_value = true;
+            } else if meta.path.is_ident("vis") {
+                let lit: LitStr = meta.value()?.parse()?;
+                struct_attrs.vis = Some(lit.parse()?);
+            } else if meta.path.is_ident("must_use") {
+                struct_attrs.must_use = true;
+            } else if meta.path.is_ident("inline") {
+                struct_attrs.inline = true;
+            } else if meta.path.is_ident("validate") {
+                let lit: LitStr = meta.value()?.parse()?;
+                struct_attrs.validate = Some(lit.parse()?);
+            } else if meta.path.is_ident("typestate") {
+                struct_attrs.typestate = true;
+                struct_attrs.owned = true;
+                struct_attrs.build_by_value = true;
+            } else if meta.path.is_ident("default") {
+                struct_attrs.default = true;
+            } else if meta.path.is_ident("unchecked") {
+                struct_attrs.unchecked = true;
+            } else if meta.path.is_ident("const") {
+                struct_attrs.const_constructor = true;
+            } else if meta.path.is_ident("clearable") {
+                struct_attrs.clearable = true;
+            } else if meta.path.is_ident("clone_build") {
+                struct_attrs.clone_build = true;
+            } else if meta.path.is_ident("boxed") {
+                struct_attrs.boxed = true;
+            } else if meta.path.is_ident("merge") {
+                struct_attrs.merge = Some(if meta.input.peek(Token![=]) {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    match lit.value().as_str() {
+                        "fill" => Merge::Fill,
+                        "override" => Merge::Override,
+                        _ => {
+                            return Err(syn::Error::new(
+                                lit.span(),
+                                "expected `merge = \"fill\"` or `\"override\"`",
+                            ))
+                        }
+                    }
+                } else {
+                    Merge::Fill
+                });
+            } else if meta.path.is_ident("cfg") {
+                let lit: LitStr = meta.value()?.parse()?;
+                struct_attrs.cfg = Some(lit.parse()?);
+            } else if meta.path.is_ident("derive") {
+                meta.parse_nested_meta(|derive| {
+                    struct_attrs.derives.push(derive.path);
+                    Ok(())
+                })?;
+            } else if let Some(key) = FIELD_ONLY_OPTIONS
+                .iter()
+                .find(|key| meta.path.is_ident(key))
+            {
+                return Err(meta.error(format!("`{}` belongs on a field, not on the struct", key)));
+            } else {
+                return Err(meta.error("unrecognized builder option"));
+            }
+            // Reported at whichever option completes the conflict. A
+            // typestate or default `build()` can't fail, so there is no error
+            // to box or to report a validation failure with.
+            if struct_attrs.typestate
+                && (struct_attrs.boxed_error || struct_attrs.validate.is_some())
+            {
+                return Err(meta.error("`typestate` cannot be combined with `error` or `validate`"));
+            }
+            if struct_attrs.default
+                && (struct_attrs.boxed_error
+                    || struct_attrs.validate.is_some()
+                    || struct_attrs.typestate)
+            {
+                return Err(meta.error(
+                    "`default` cannot be combined with `error`, `validate` or `typestate`",
+                ));
+            }
+            // Without any checks to skip, `build_unchecked()` would just be
+            // `build()`.
+            if struct_attrs.unchecked && (struct_attrs.typestate || struct_attrs.default) {
+                return Err(
+                    meta.error("`unchecked` cannot be combined with `typestate` or `default`")
+                );
+            }
+            // Merging would have to decide the builder's type from values.
+            if struct_attrs.merge.is_some() && struct_attrs.typestate {
+                return Err(meta.error("`merge` cannot be combined with `typestate`"));
+            }
+            Ok(())
+        })?;
     }
-    let Expr::Assign(assign) = attr.parse_args()? else {
-        return err(attr.span());
-    };
+    Ok(struct_attrs)
+}
 
-    let Expr::Path(lhs_path) = &*assign.left else {
-        return err(assign.span());
+// The spellings of a type path we recognize, bare or fully qualified.
+const OPTION_PATHS: &[&str] = &["Option", "std::option::Option", "core::option::Option"];
+const VEC_PATHS: &[&str] = &["Vec", "std::vec::Vec", "alloc::vec::Vec"];
+const HASH_MAP_PATHS: &[&str] = &[
+    "HashMap",
+    "std::collections::HashMap",
+    "std::collections::hash_map::HashMap",
+];
+const BTREE_MAP_PATHS: &[&str] = &[
+    "BTreeMap",
+    "std::collections::BTreeMap",
+    "std::collections::btree_map::BTreeMap",
+];
+const HASH_SET_PATHS: &[&str] = &[
+    "HashSet",
+    "std::collections::HashSet",
+    "std::collections::hash_set::HashSet",
+];
+const VEC_DEQUE_PATHS: &[&str] = &[
+    "VecDeque",
+    "std::collections::VecDeque",
+    "std::collections::vec_deque::VecDeque",
+];
+const LINKED_LIST_PATHS: &[&str] = &[
+    "LinkedList",
+    "std::collections::LinkedList",
+    "std::collections::linked_list::LinkedList",
+];
+const PHANTOM_DATA_PATHS: &[&str] = &[
+    "PhantomData",
+    "std::marker::PhantomData",
+    "core::marker::PhantomData",
+];
+const BOX_PATHS: &[&str] = &["Box", "std::boxed::Box", "alloc::boxed::Box"];
+const RC_PATHS: &[&str] = &["Rc", "std::rc::Rc", "alloc::rc::Rc"];
+const ARC_PATHS: &[&str] = &["Arc", "std::sync::Arc", "alloc::sync::Arc"];
+
+// If `ty` is written as `Wrapper<T>` with `Wrapper` one of `known_paths`,
+// return `T`.
+fn generic_arg<'a>(ty: &'a Type, known_paths: &[&str]) -> Option<&'a Type> {
+    generic_args(ty, known_paths)?.first().copied()
+}
+
+// If `ty` is written as `Wrapper<T, U, ...>` with `Wrapper` one of
+// `known_paths`, return the type arguments. Just following the tree in
+// 06-optional-field.rs, except that the path may have more than one segment.
+fn generic_args<'a>(ty: &'a Type, known_paths: &[&str]) -> Option<Vec<&'a Type>> {
+    let Type::Path(TypePath {
+        qself: None,
+        path: Path { segments, .. },
+    }) = ty
+    else {
+        return None;
     };
-    if !lhs_path.path.is_ident("each") {
-        return err(lhs_path.span());
+    let last = segments.last()?;
+    if segments
+        .iter()
+        .take(segments.len() - 1)
+        .any(|segment| !matches!(segment.arguments, PathArguments::None))
+    {
+        return None;
+    }
+    let path = segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::");
+    if !known_paths.contains(&path.as_str()) {
+        return None;
     }
-    let Expr::Lit(ExprLit {
-        lit: Lit::Str(str), ..
-    }) = &*assign.right
+    let PathSegment {
+        arguments: PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }),
+        ..
+    } = last
     else {
-        return err(assign.span());
+        return None;
+    };
+    let tys: Vec<_> = args
+        .iter()
+        .filter_map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        })
+        .collect();
+    if tys.is_empty() {
+        return None;
+    }
+    Some(tys)
+}
+
+// If `ty` is a `Box<T>`, `Rc<T>` or `Arc<T>`, return `T` and the fully
+// qualified wrapper path, whose `new` wraps a `T`.
+fn wrapper_arg(ty: &Type) -> Option<(&Type, Path)> {
+    if let Some(inner_ty) = generic_arg(ty, BOX_PATHS) {
+        Some((inner_ty, parse_quote! { std::boxed::Box }))
+    } else if let Some(inner_ty) = generic_arg(ty, RC_PATHS) {
+        Some((inner_ty, parse_quote! { std::rc::Rc }))
+    } else {
+        generic_arg(ty, ARC_PATHS).map(|inner_ty| (inner_ty, parse_quote! { std::sync::Arc }))
+    }
+}
+
+// The builder of a `#[builder(nested)]` field's type, assuming it uses the
+// default name: `inner::Config<T>` has the builder `inner::ConfigBuilder<T>`.
+fn nested_builder_ty(ty: &Type) -> Option<Type> {
+    let Type::Path(mut type_path @ TypePath { qself: None, .. }) = ty.clone() else {
+        return None;
     };
-    Ok(Some(str.value()))
+    let last = type_path.path.segments.last_mut()?;
+    last.ident = format_ident!("{}Builder", last.ident);
+    Some(Type::Path(type_path))
+}
+
+// `current_dir` becomes `CurrentDir`, and `r#type` becomes `Type`, for
+// naming error variants.
+fn upper_camel_case(ident: &Ident) -> String {
+    ident
+        .unraw()
+        .to_string()
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+// Whether any `#[derive(...)]` among `attrs` names the trait `name`.
+fn derives_trait(attrs: &[Attribute], name: &str) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .any(|path| path.segments.last().is_some_and(|last| last.ident == name))
+}
+
+// A member for a field of the builder's own, after the struct's fields:
+// `name`, lengthened until no field of the struct has it, or the next position
+// in a tuple struct.
+fn hidden_member(fields: &Punctuated<Field, Token![,]>, tuple: bool, name: &str) -> Member {
+    if tuple {
+        return Member::Unnamed(Index::from(fields.len()));
+    }
+    let mut name = name.to_owned();
+    while fields.iter().any(|field| {
+        field
+            .ident
+            .as_ref()
+            .is_some_and(|ident| ident.unraw() == name)
+    }) {
+        name.push('_');
+    }
+    Member::Named(format_ident!("{}", name))
+}
+
+fn hidden_field(member: &Member, ty: Type) -> Field {
+    Field {
+        attrs: vec![],
+        vis: Visibility::Inherited,
+        mutability: FieldMutability::None,
+        ident: match member {
+            Member::Named(ident) => Some(ident.clone()),
+            Member::Unnamed(_) => None,
+        },
+        colon_token: match member {
+            Member::Named(_) => Some(Default::default()),
+            Member::Unnamed(_) => None,
+        },
+        ty,
+    }
+}
+
+// Replaces `Self` in a field type with the struct's own type, which is what it
+// meant there, as opposed to the builder it would mean on a builder's field
+// or setter.
+struct ReplaceSelf(Type);
+
+impl VisitMut for ReplaceSelf {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        match ty {
+            Type::Path(TypePath { qself: None, path }) if path.is_ident("Self") => {
+                *ty = self.0.clone();
+            }
+            _ => syn::visit_mut::visit_type_mut(self, ty),
+        }
+    }
 }
 
 #[proc_macro_derive(Builder, attributes(builder))]
 pub fn derive(input: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(input as DeriveInput);
+    expand(parse_macro_input!(input as DeriveInput)).into()
+}
+
+// The derive itself, on `proc_macro2` tokens so that it can run outside of a
+// macro invocation too, as in the tests below.
+fn expand(input: DeriveInput) -> proc_macro2::TokenStream {
     let name = input.ident.clone();
+    // The builder struct is a copy of the input, so it carries the same
+    // generics. Every impl we emit needs them too.
+    let generics = input.generics.clone();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let builder_ident = format_ident!("{}{}", input.ident, "Builder");
+    let struct_attrs = match parse_struct_attrs(&input.attrs) {
+        Ok(struct_attrs) => struct_attrs,
+        Err(e) => return e.to_compile_error(),
+    };
+
+    let builder_ident = struct_attrs
+        .name
+        .unwrap_or_else(|| format_ident!("{}{}", input.ident, "Builder"));
+
+    let error_ident = format_ident!("{}Error", builder_ident);
+    let vis = input.vis.clone();
 
     let mut builder_struct = input;
     builder_struct.ident = builder_ident.clone();
+    // Our own attributes mean nothing on the builder struct, and neither do
+    // most others, like a `repr` or the struct's docs. Later derives still
+    // apply, and a `cfg` gates the builder along with the struct.
+    builder_struct
+        .attrs
+        .retain(|attr| attr.path().is_ident("derive") || attr.path().is_ident("cfg"));
+    if !struct_attrs.derives.is_empty() {
+        let derives = &struct_attrs.derives;
+        builder_struct
+            .attrs
+            .push(parse_quote! { #[derive(#(#derives),*)] });
+    }
 
     #[derive(Clone)]
     enum SpecialFieldTypes {
         Option,
         Vec,
+        // `Option<Vec<T>>`: stays `None` unless something was set or pushed.
+        OptionVec,
+        // `HashMap<K, V>` or `BTreeMap<K, V>`: the key type and the whole map
+        // type. The value type is kept where the element type of a `Vec`
+        // would be.
+        Map(Box<Type>, Box<Type>),
+        // `HashSet<T>`: the whole set type.
+        Set(Box<Type>),
+        // `VecDeque<T>`: the whole queue type.
+        Deque(Box<Type>),
+        // `LinkedList<T>`: the whole list type.
+        List(Box<Type>),
+        // `#[builder(extend)]`: the whole collection type, which is also kept
+        // where the element type would be, since it isn't known.
+        Extend(Box<Type>),
+        // `#[builder(unwrap)]` on a `Box<T>`, `Rc<T>` or `Arc<T>`: the
+        // wrapper path. The builder stores the inner `T`.
+        Wrapper(Box<Path>),
+        // The same inside an `Option`, which stays `None` unless set.
+        OptionWrapper(Box<Path>),
         Unknown,
     }
 
+    // Everything the generated code needs to know about one field, collected
+    // in a single pass so that nothing about one field can end up paired
+    // with another.
+    struct FieldInfo {
+        // How the builder accesses the field: its name or, for tuple
+        // structs, its positional index.
+        member: Member,
+        // What the setters are named after: the field name, or `field_N`
+        // for positional fields.
+        ident: Ident,
+        // The type a setter takes: the field's own, or the element or inner
+        // type that `speciality` tells apart.
+        ty: Type,
+        speciality: SpecialFieldTypes,
+        attrs: FieldAttrs,
+        // The field's `cfg`s, lint levels and docs, which go on each of its
+        // setters.
+        setter_attrs: Vec<Attribute>,
+        // The field's `cfg`s alone, which gate anything else about it.
+        cfgs: Vec<Attribute>,
+        // The field's `allow`, `warn`, `deny` and `forbid` attributes, which
+        // go on every method generated for it.
+        lints: Vec<Attribute>,
+        vis: Visibility,
+    }
+
     // Right, builder_struct is the same as our input struct.
     // Modify it to add `Option<>` around each field.
-    let mut field_idents = vec![];
-    let mut field_tys = vec![];
-    let mut vec_each = vec![];
-    if let Data::Struct(DataStruct {
-        fields: syn::Fields::Named(ref mut fields),
-        ..
-    }) = builder_struct.data
-    {
-        for field in fields.named.iter_mut() {
-            let ty = &field.ty;
-            // Check if this field is already an `Option`.
-            // Just following the tree in 06-optional-field.rs.
-            let mut is_option = false;
-            if let Type::Path(TypePath {
-                qself: None,
-                path: Path { segments, .. },
-            }) = ty
-            {
-                if let Some(PathSegment {
-                    ident,
-                    arguments:
-                        PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }),
-                }) = segments.first()
-                {
-                    if let Some(GenericArgument::Type(inner_ty)) = args.first() {
-                        if ident == "Option" {
-                            is_option = true;
-                            field_tys.push((inner_ty.clone(), SpecialFieldTypes::Option));
-                        }
-                    }
-                }
-            }
-            let mut is_vec = false;
-            if let Type::Path(TypePath {
-                qself: None,
-                path: Path { segments, .. },
-            }) = ty
-            {
-                if let Some(PathSegment {
-                    ident,
-                    arguments:
-                        PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }),
-                }) = segments.first()
-                {
-                    if let Some(GenericArgument::Type(inner_ty)) = args.first() {
-                        if ident == "Vec" {
-                            is_vec = true;
-                            field_tys.push((inner_ty.clone(), SpecialFieldTypes::Vec));
-                        }
-                    }
-                }
+    let mut field_infos: Vec<FieldInfo> = vec![];
+    // Decided by the kind of struct, since a tuple struct may have no fields
+    // to tell by.
+    let tuple = matches!(
+        builder_struct.data,
+        Data::Struct(DataStruct {
+            fields: Fields::Unnamed(_),
+            ..
+        })
+    );
+    let fields = match builder_struct.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(ref mut fields),
+            ..
+        }) => &mut fields.named,
+        Data::Struct(DataStruct {
+            fields: Fields::Unnamed(ref mut fields),
+            ..
+        }) => &mut fields.unnamed,
+        // A union holds just one of its fields, so there is nothing to build
+        // up field by field.
+        Data::Union(_) => {
+            return syn::Error::new_spanned(&name, "#[derive(Builder)] doesn't support unions")
+                .to_compile_error()
+        }
+        _ => {
+            return syn::Error::new_spanned(
+                &name,
+                "#[derive(Builder)] only supports structs with named or tuple fields",
+            )
+            .to_compile_error()
+        }
+    };
+    let mut replace_self = ReplaceSelf(parse_quote! { #name #ty_generics });
+    for (field_idx, field) in fields.iter_mut().enumerate() {
+        replace_self.visit_type_mut(&mut field.ty);
+        let ty = &field.ty;
+        let mut parsed_attrs = match parse_field_attrs(&field.attrs) {
+            Ok(parsed_attrs) => parsed_attrs,
+            Err(e) => return e.to_compile_error(),
+        };
+        // There is nothing to set in a marker, so it's handled like a
+        // skipped field, whose `Default` is the `PhantomData` itself.
+        if generic_arg(ty, PHANTOM_DATA_PATHS).is_some() {
+            parsed_attrs.skip = true;
+        }
+        // Check if this field is already an `Option`, a `Vec`, or an
+        // `Option<Vec<_>>` that still supports one-at-a-time pushes.
+        let speciality = if parsed_attrs.required {
+            if generic_arg(ty, OPTION_PATHS).is_none() {
+                return syn::Error::new_spanned(ty, "`required` only applies to `Option` fields")
+                    .to_compile_error();
             }
-            if is_vec {
-                let parsed_attr_opt = match match_vec_each(&field.attrs) {
-                    Ok(attr_opt) => attr_opt,
-                    Err(e) => return e.to_compile_error().into(),
-                };
-                if let Some(each_name) = parsed_attr_opt {
-                    vec_each.push(Some(each_name));
+            // Just another required field, whose value happens to be an
+            // `Option`.
+            (ty.clone(), SpecialFieldTypes::Unknown)
+        } else if parsed_attrs.unwrap {
+            let optional = generic_arg(ty, OPTION_PATHS);
+            let Some((inner_ty, wrapper)) = wrapper_arg(optional.unwrap_or(ty)) else {
+                return syn::Error::new_spanned(
+                    ty,
+                    "`unwrap` requires a `Box`, `Rc` or `Arc` field, or an `Option` of one",
+                )
+                .to_compile_error();
+            };
+            let wrapper = Box::new(wrapper);
+            (
+                inner_ty.clone(),
+                if optional.is_some() {
+                    SpecialFieldTypes::OptionWrapper(wrapper)
                 } else {
-                    vec_each.push(None);
-                }
+                    SpecialFieldTypes::Wrapper(wrapper)
+                },
+            )
+        } else if parsed_attrs.extend {
+            (ty.clone(), SpecialFieldTypes::Extend(Box::new(ty.clone())))
+        } else if let Some(collection) = &parsed_attrs.collection {
+            // The alias hides the element type, but the collection knows it.
+            let elem_ty: Type = parse_quote! { <#ty as std::iter::IntoIterator>::Item };
+            let speciality = match collection.as_str() {
+                "vec" => SpecialFieldTypes::Vec,
+                "vec_deque" => SpecialFieldTypes::Deque(Box::new(ty.clone())),
+                _ => SpecialFieldTypes::Set(Box::new(ty.clone())),
+            };
+            (elem_ty, speciality)
+        } else if let Some(inner_ty) = generic_arg(ty, OPTION_PATHS) {
+            if let Some(elem_ty) = generic_arg(inner_ty, VEC_PATHS) {
+                (elem_ty.clone(), SpecialFieldTypes::OptionVec)
             } else {
-                vec_each.push(None);
+                (inner_ty.clone(), SpecialFieldTypes::Option)
+            }
+        } else if let Some(elem_ty) = generic_arg(ty, VEC_PATHS) {
+            (elem_ty.clone(), SpecialFieldTypes::Vec)
+        } else if let Some([key_ty, value_ty, ..]) = generic_args(ty, HASH_MAP_PATHS)
+            .or_else(|| generic_args(ty, BTREE_MAP_PATHS))
+            .as_deref()
+        {
+            (
+                (*value_ty).clone(),
+                SpecialFieldTypes::Map(Box::new((*key_ty).clone()), Box::new(ty.clone())),
+            )
+        } else if let Some(elem_ty) = generic_arg(ty, HASH_SET_PATHS) {
+            (
+                elem_ty.clone(),
+                SpecialFieldTypes::Set(Box::new(ty.clone())),
+            )
+        } else if let Some(elem_ty) = generic_arg(ty, VEC_DEQUE_PATHS) {
+            (
+                elem_ty.clone(),
+                SpecialFieldTypes::Deque(Box::new(ty.clone())),
+            )
+        } else if let Some(elem_ty) = generic_arg(ty, LINKED_LIST_PATHS) {
+            (
+                elem_ty.clone(),
+                SpecialFieldTypes::List(Box::new(ty.clone())),
+            )
+        } else {
+            (ty.clone(), SpecialFieldTypes::Unknown)
+        };
+        let is_option = matches!(
+            speciality.1,
+            SpecialFieldTypes::Option | SpecialFieldTypes::OptionVec
+        );
+        let is_collection = matches!(
+            speciality.1,
+            SpecialFieldTypes::Vec
+                | SpecialFieldTypes::OptionVec
+                | SpecialFieldTypes::Map(..)
+                | SpecialFieldTypes::Set(..)
+                | SpecialFieldTypes::Deque(..)
+                | SpecialFieldTypes::List(..)
+                | SpecialFieldTypes::Extend(..)
+        );
+        let is_wrapper = matches!(
+            speciality.1,
+            SpecialFieldTypes::Wrapper(_) | SpecialFieldTypes::OptionWrapper(_)
+        );
+        match &parsed_attrs.each {
+            Some(each) if !is_collection => {
+                return syn::Error::new(
+                    each.span(),
+                    "`each` can only be used on a `Vec` or other collection field",
+                )
+                .to_compile_error()
+            }
+            Some(each)
+                if field_infos.iter().any(|info| {
+                    info.attrs
+                        .each
+                        .as_ref()
+                        .is_some_and(|other| other.value() == each.value())
+                }) =>
+            {
+                return syn::Error::new(
+                    each.span(),
+                    format!(
+                        "`each` name `{}` is already used by another field",
+                        each.value()
+                    ),
+                )
+                .to_compile_error()
             }
+            _ => {}
+        }
+        if parsed_attrs.nested && (is_collection || nested_builder_ty(&speciality.0).is_none()) {
+            return syn::Error::new_spanned(
+                ty,
+                "`nested` requires a field whose type derives `Builder`",
+            )
+            .to_compile_error();
+        }
+
+        if parsed_attrs.opt_setter && is_collection {
+            return syn::Error::new_spanned(ty, "`opt_setter` doesn't apply to collection fields")
+                .to_compile_error();
+        }
+
+        // Doc comments on the field document its setters instead, and go
+        // along with its `cfg`s.
+        let docs: Vec<Attribute> = field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc"))
+            .cloned()
+            .collect();
+        // A `cfg` on the field also gates everything generated for it.
+        let cfgs: Vec<Attribute> = field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("cfg"))
+            .cloned()
+            .collect();
+        // Lints allowed on the field would fire in its setters otherwise,
+        // since they repeat its name and type. An `expect` stays behind: on
+        // every method that doesn't trigger the lint, a copy would warn as
+        // unfulfilled.
+        let is_lint = |attr: &Attribute| {
+            ["allow", "warn", "deny", "forbid"]
+                .iter()
+                .any(|lint| attr.path().is_ident(lint))
+        };
+        let lints: Vec<Attribute> = field
+            .attrs
+            .iter()
+            .filter(|attr| is_lint(attr))
+            .cloned()
+            .collect();
+        let setter_attrs = cfgs.iter().chain(&lints).chain(&docs).cloned().collect();
+        let vis = field.vis.clone();
 
-            // We don't want attributes on struct Builder
-            field.attrs.clear();
+        // We don't want other attributes on struct Builder
+        field
+            .attrs
+            .retain(|attr| attr.path().is_ident("cfg") || (!parsed_attrs.skip && is_lint(attr)));
+        if parsed_attrs.skip {
+            // A skipped field is never set or read, but keeping its storage
+            // means any generic parameters it mentions stay used. It drops
+            // the field's lint levels, which could `forbid` this `allow`.
+            field.attrs.push(parse_quote! { #[allow(dead_code)] });
+        }
 
-            if !is_option {
-                if !is_vec {
-                    field_tys.push((ty.clone(), SpecialFieldTypes::Unknown));
+        if !is_option {
+            // This is not an Option, so add Option wrapper. An unwrapped
+            // field stores the inner value until `build()`, even inside an
+            // `Option`.
+            let stored_ty = if is_wrapper { &speciality.0 } else { &field.ty };
+            let optioned_ty: Type = parse_quote! { std::option::Option<#stored_ty> };
+            *field = Field {
+                ty: optioned_ty,
+                ..field.clone()
+            };
+        }
+        let (member, ident) = match &field.ident {
+            Some(ident) => (Member::Named(ident.clone()), ident.clone()),
+            None => (
+                Member::Unnamed(Index::from(field_idx)),
+                format_ident!("field_{}", field_idx),
+            ),
+        };
+        let (ty, speciality) = speciality;
+        field_infos.push(FieldInfo {
+            member,
+            ident,
+            ty,
+            speciality,
+            attrs: parsed_attrs,
+            setter_attrs,
+            lints,
+            cfgs,
+            vis,
+        });
+    }
+    // For repetitions in `quote!`, which need the parts side by side.
+    let field_members: Vec<&Member> = field_infos.iter().map(|info| &info.member).collect();
+    let field_cfgs: Vec<&Vec<Attribute>> = field_infos.iter().map(|info| &info.cfgs).collect();
+
+    let build_ident = struct_attrs
+        .build_name
+        .clone()
+        .unwrap_or_else(|| format_ident!("build"));
+    // Collections get an `..._extend` setter, unless guarded by `validate`
+    // or `set_once`, which only their all-at-once setter goes through.
+    let has_extend_setter = |info: &FieldInfo| {
+        matches!(
+            info.speciality,
+            SpecialFieldTypes::Vec
+                | SpecialFieldTypes::OptionVec
+                | SpecialFieldTypes::Map(..)
+                | SpecialFieldTypes::Set(_)
+                | SpecialFieldTypes::Deque(_)
+                | SpecialFieldTypes::List(_)
+                | SpecialFieldTypes::Extend(_)
+        ) && info.attrs.validate.is_none()
+            && info.attrs.set_once.is_none()
+    };
+
+    // No two generated methods can share a name. Rather than the compiler's
+    // report of a duplicate definition somewhere in the generated code, the
+    // error points at the name that causes it.
+    let mut builder_method_names = vec!["reset", "with"];
+    if struct_attrs.unchecked {
+        builder_method_names.push("build_unchecked");
+    }
+    if struct_attrs.clone_build {
+        builder_method_names.push("build_clone");
+    }
+    if struct_attrs.boxed {
+        builder_method_names.push("build_boxed");
+    }
+    if !struct_attrs.typestate {
+        builder_method_names.push("merge");
+    }
+    let build_name = build_ident.unraw().to_string();
+    if builder_method_names.contains(&build_name.as_str()) {
+        return syn::Error::new(
+            build_ident.span(),
+            format!(
+                "`build_name` `{}` collides with the builder's own `{}()` method",
+                build_name, build_name
+            ),
+        )
+        .to_compile_error();
+    }
+    builder_method_names.push(&build_name);
+    // What each field's methods are called, and the field they belong to.
+    let mut field_method_names: Vec<(String, &Ident)> = vec![];
+    for info in field_infos.iter().filter(|info| !info.attrs.skip) {
+        let attrs = &info.attrs;
+        let setter_ident = attrs.rename.as_ref().unwrap_or(&info.ident);
+        let each_ident = attrs.each.as_ref().map(|each| parse_ident(each).unwrap());
+        for ident in std::iter::once(setter_ident).chain(&each_ident) {
+            if builder_method_names.contains(&ident.unraw().to_string().as_str()) {
+                return syn::Error::new(
+                    ident.span(),
+                    format!(
+                        "setter `{}` collides with the builder's own `{}()` method",
+                        ident, ident
+                    ),
+                )
+                .to_compile_error();
+            }
+        }
+        let setter_name = setter_ident.unraw().to_string();
+        let mut names: Vec<String> = each_ident
+            .iter()
+            .map(|each| each.unraw().to_string())
+            .collect();
+        // An `each` setter of the same name replaces the all-at-once one.
+        if !names.contains(&setter_name) {
+            names.push(setter_name.clone());
+        }
+        names.push(format!("is_{}_set", setter_name));
+        if has_extend_setter(info) {
+            names.push(format!("{}_extend", setter_name));
+        }
+        if attrs.opt_setter {
+            names.push(format!("{}_opt", setter_name));
+        }
+        if struct_attrs.clearable {
+            names.push(format!("clear_{}", setter_name));
+        }
+        for method_name in names {
+            let error = if builder_method_names.contains(&method_name.as_str()) {
+                format!(
+                    "`{}()`, generated for `{}`, collides with the builder's own `{}()` method",
+                    method_name, setter_name, method_name
+                )
+            } else if let Some((_, other)) = field_method_names
+                .iter()
+                .find(|(other_name, _)| *other_name == method_name)
+            {
+                format!(
+                    "`{}()` would be generated both for `{}` and for `{}`",
+                    method_name,
+                    other.unraw(),
+                    setter_name
+                )
+            } else {
+                field_method_names.push((method_name, setter_ident));
+                continue;
+            };
+            return syn::Error::new(setter_ident.span(), error).to_compile_error();
+        }
+    }
+
+    // Fields that `build()` can't fill in unless they were set.
+    let is_required = |info: &FieldInfo| {
+        !info.attrs.skip
+            && info.attrs.default.is_none()
+            && matches!(
+                info.speciality,
+                SpecialFieldTypes::Wrapper(_) | SpecialFieldTypes::Unknown
+            )
+    };
+    // With `typestate`, the builder gets one type parameter per required
+    // field, which is either the `Set` or the `Unset` marker, and a
+    // `PhantomData` field to hold them. Everything else sees the original
+    // generics.
+    let required: Vec<bool> = field_infos
+        .iter()
+        .map(|info| struct_attrs.typestate && is_required(info))
+        .collect();
+    // A state parameter can't be gated, so neither can a required field.
+    if let Some((info, _)) = field_infos
+        .iter()
+        .zip(&required)
+        .find(|&(info, &required)| required && !info.cfgs.is_empty())
+    {
+        return syn::Error::new_spanned(
+            &info.cfgs[0],
+            "`typestate` doesn't support `cfg` on required fields",
+        )
+        .to_compile_error();
+    }
+    // Numbered rather than named after the fields, since `addr_1` and
+    // `addr1` would both give `__Addr1`.
+    let state_params: Vec<Ident> = required
+        .iter()
+        .filter(|&&required| required)
+        .enumerate()
+        .map(|(i, _)| format_ident!("__State{}", i))
+        .collect();
+    let set_ident = format_ident!("{}Set", builder_ident);
+    let unset_ident = format_ident!("{}Unset", builder_ident);
+    // Parameters after a defaulted one need defaults too, so then the
+    // states default to unset.
+    let has_defaults = generics.params.iter().any(|param| match param {
+        GenericParam::Type(param) => param.default.is_some(),
+        GenericParam::Const(param) => param.default.is_some(),
+        GenericParam::Lifetime(_) => false,
+    });
+    let mut state_generics = generics.clone();
+    state_generics
+        .params
+        .extend(state_params.iter().map(|param| -> GenericParam {
+            if has_defaults {
+                parse_quote! { #param = #unset_ident }
+            } else {
+                parse_quote! { #param }
+            }
+        }));
+    let (state_impl_generics, state_ty_generics, _) = state_generics.split_for_impl();
+    // The original generic arguments, to spell out the builder type with
+    // concrete states, like `CommandBuilder<T, CommandBuilderSet>`.
+    let ty_args: Vec<_> = generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Lifetime(param) => param.lifetime.to_token_stream(),
+            GenericParam::Type(param) => param.ident.to_token_stream(),
+            GenericParam::Const(param) => param.ident.to_token_stream(),
+        })
+        .collect();
+    let builder_ty_with = |states: &[&Ident]| {
+        quote! { #builder_ident<#( #ty_args, )* #( #states ),*> }
+    };
+    let typestate_member = struct_attrs
+        .typestate
+        .then(|| hidden_member(fields, tuple, "__typestate"));
+    if let Some(member) = &typestate_member {
+        fields.push(hidden_field(
+            member,
+            parse_quote! { std::marker::PhantomData<(#( #state_params, )*)> },
+        ));
+        builder_struct.generics = state_generics.clone();
+    }
+    let typestate_init = typestate_member
+        .as_ref()
+        .map(|member| quote! { #member: std::marker::PhantomData, });
+    // A builder that `build()` took the fields out of remembers it, so that
+    // building again reports that instead of the fields as not initialized.
+    // Builders that are consumed by `build()`, or whose `build()` can't
+    // fail, don't need to.
+    let consumed_member = (!struct_attrs.build_by_value
+        && !struct_attrs.default
+        && field_infos.iter().any(is_required))
+    .then(|| hidden_member(fields, tuple, "__consumed"));
+    if let Some(member) = &consumed_member {
+        fields.push(hidden_field(member, parse_quote! { bool }));
+    }
+    let consumed_init = consumed_member
+        .as_ref()
+        .map(|member| quote! { #member: false, });
+    // The builder's own fields are no business of its `Debug` output, so a
+    // derived `Debug` gives way to one that prints the struct's fields alone,
+    // like the derive would, with the same bounds.
+    let mut derives_debug = false;
+    if typestate_member.is_some() || consumed_member.is_some() {
+        for attr in builder_struct
+            .attrs
+            .iter_mut()
+            .filter(|attr| attr.path().is_ident("derive"))
+        {
+            let paths = match attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)
+            {
+                Ok(paths) => paths,
+                Err(e) => return e.to_compile_error(),
+            };
+            let (debug, others): (Vec<Path>, Vec<Path>) = paths.into_iter().partition(|path| {
+                path.segments
+                    .last()
+                    .is_some_and(|last| last.ident == "Debug")
+            });
+            derives_debug |= !debug.is_empty();
+            *attr = parse_quote! { #[derive(#(#others),*)] };
+        }
+    }
+    let debug_impl = if derives_debug {
+        let builder_name = builder_ident.unraw().to_string();
+        let field_debugs = field_infos.iter().map(|info| {
+            let field_member = &info.member;
+            let cfgs = &info.cfgs;
+            match field_member {
+                Member::Named(ident) => {
+                    let field_name = ident.unraw().to_string();
+                    quote! { #( #cfgs )* debug.field(#field_name, &self.#field_member); }
+                }
+                Member::Unnamed(_) => {
+                    quote! { #( #cfgs )* debug.field(&self.#field_member); }
+                }
+            }
+        });
+        let debug_start = if tuple {
+            quote! { f.debug_tuple(#builder_name) }
+        } else {
+            quote! { f.debug_struct(#builder_name) }
+        };
+        let (debug_impl_generics, debug_ty_generics, debug_where_clause) =
+            builder_struct.generics.split_for_impl();
+        let mut debug_where_clause = debug_where_clause
+            .cloned()
+            .unwrap_or_else(|| parse_quote! { where });
+        for param in generics.type_params() {
+            let param_ident = &param.ident;
+            debug_where_clause
+                .predicates
+                .push(parse_quote! { #param_ident: std::fmt::Debug });
+        }
+        quote! {
+            impl #debug_impl_generics std::fmt::Debug for #builder_ident #debug_ty_generics #debug_where_clause {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    let mut debug = #debug_start;
+                    #( #field_debugs )*
+                    debug.finish()
                 }
-                // This is not an Option, so add Option wrapper.
-                let optioned_ty: Type = parse_quote! { std::option::Option<#ty> };
-                *field = Field {
-                    ty: optioned_ty,
-                    ..field.clone()
-                };
             }
-            field_idents.push(field.ident.clone().unwrap());
         }
     } else {
-        panic!("#[derive(Builder)] only works on named structs")
-    }
+        quote! {}
+    };
+    // Moves every field into a builder of whatever state the context asks
+    // for, which is how a setter changes the builder's type.
+    let transition = quote! {
+        #builder_ident {
+            #( #( #field_cfgs )* #field_members: self.#field_members, )*
+            #typestate_init
+        }
+    };
 
     let mut output = quote! {};
 
-    let builder_fn = quote! {
-        impl #name {
-            fn builder() -> #builder_ident {
-                #builder_ident {
-                    #( #field_idents : std::option::Option::None ), *
-                }
+    // `build()` and `reset()` are `pub` unless `#[builder(vis = "...")]` says
+    // otherwise. Setters follow the same override, or else their field.
+    let method_vis = struct_attrs
+        .vis
+        .clone()
+        .unwrap_or_else(|| parse_quote! { pub });
+    // `builder()` is as visible as the struct, so the builder is reachable
+    // wherever the struct is.
+    let constructor_vis = struct_attrs.vis.clone().unwrap_or_else(|| vis.clone());
+    let must_use = if struct_attrs.must_use {
+        quote! { #[must_use] }
+    } else {
+        quote! {}
+    };
+    let inline = if struct_attrs.inline {
+        quote! { #[inline] }
+    } else {
+        quote! {}
+    };
+    let all_unset = vec![&unset_ident; state_params.len()];
+    let constructor_ret = if struct_attrs.typestate {
+        builder_ty_with(&all_unset)
+    } else {
+        quote! { #builder_ident #ty_generics }
+    };
+    let constructor_ident = struct_attrs
+        .constructor
+        .clone()
+        .unwrap_or_else(|| format_ident!("builder"));
+    // A builder with nothing set yet.
+    let builder_init = quote! {
+        #builder_ident {
+            #( #( #field_cfgs )* #field_members : std::option::Option::None, )*
+            #typestate_init
+            #consumed_init
+        }
+    };
+    // Every field starts out as `None`, which is fine in a constant. `reset()`
+    // can't follow, since it drops the values it clears.
+    let constness = if struct_attrs.const_constructor {
+        quote! { const }
+    } else {
+        quote! {}
+    };
+    let mut builder_fn = quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #must_use
+            #inline
+            #constructor_vis #constness fn #constructor_ident() -> #constructor_ret {
+                #builder_init
             }
         }
     };
+    // The same through `Default`, unless the builder derives it already,
+    // whether through `#[builder(derive(...))]` or a later `#[derive(...)]`
+    // on the struct.
+    if !derives_trait(&builder_struct.attrs, "Default") {
+        builder_fn.extend(quote! {
+            impl #impl_generics std::default::Default for #constructor_ret #where_clause {
+                fn default() -> Self {
+                    #builder_init
+                }
+            }
+        });
+    }
 
+    // How every setter receives and returns the builder.
+    // Owned setters spell out the builder type rather than `Self`, so that it
+    // shows in docs and error messages.
+    let (setter_self, setter_ret) = if struct_attrs.typestate {
+        (
+            quote! { mut self },
+            quote! { #builder_ident #state_ty_generics },
+        )
+    } else if struct_attrs.owned {
+        (quote! { mut self }, quote! { #builder_ident #ty_generics })
+    } else {
+        (quote! { &mut self }, quote! { &mut Self })
+    };
+    // For each field in declaration order: the `each` setter, the all-at-once
+    // setter, the `..._opt` setter, the `..._extend` setter, `is_..._set()`,
+    // then `clear_...()`. This order shows in `cargo expand` and in the
+    // builder's docs, so the `method_order` test below pins it.
     let mut builder_methods = quote! {};
-    for field_idx in 0..field_idents.len() {
-        let field_ident = field_idents[field_idx].clone();
-        let (field_ty, field_speciality) = field_tys[field_idx].clone();
+    for (field_idx, info) in field_infos.iter().enumerate() {
+        if info.attrs.skip {
+            continue;
+        }
+        let method_vis = struct_attrs.vis.clone().unwrap_or_else(|| info.vis.clone());
+        let field_member = info.member.clone();
+        let field_ident = info.ident.clone();
+        let field_ty = info.ty.clone();
+        let field_speciality = info.speciality.clone();
+        let attrs = &info.setter_attrs;
+        let setter_ident = info
+            .attrs
+            .rename
+            .clone()
+            .unwrap_or_else(|| field_ident.clone());
+        // Setting a required field of a typestate builder marks it as set,
+        // which changes the builder's type. Clearing it marks it as unset.
+        let field_state = |state: &Ident| {
+            if required[field_idx] {
+                let state_idx = required[..field_idx].iter().filter(|&&r| r).count();
+                let mut states: Vec<&Ident> = state_params.iter().collect();
+                states[state_idx] = state;
+                (builder_ty_with(&states), transition.clone())
+            } else {
+                (setter_ret.clone(), quote! { self })
+            }
+        };
+        let (field_ret, field_done) = field_state(&set_ident);
+        // A `set_once` setter checks before anything else, so that a second
+        // call changes nothing.
+        let set_once_check = info.attrs.set_once.map(|set_once| {
+            let message = format!(
+                "{}: `{}` was already set",
+                builder_ident.unraw(),
+                setter_ident.unraw(),
+            );
+            let refusal = match set_once {
+                SetOnce::Panic => quote! { panic!(#message) },
+                SetOnce::Error => quote! {
+                    return std::result::Result::Err(std::convert::From::from(#message))
+                },
+            };
+            quote! {
+                if self.#field_member.is_some() {
+                    #refusal;
+                }
+            }
+        });
         let mut generate_all_at_once = true;
-        if let Some(each_name) = vec_each[field_idx].clone() {
-            let fn_name = format_ident!("{}", each_name);
-            if fn_name == field_ident {
+        if let Some(each) = &info.attrs.each {
+            let fn_name = format_ident!("{}", each.value());
+            // An `each` setter named like the field replaces the all-at-once
+            // setter, since the two can't share a name. Stable proc macros
+            // can't emit warnings, so this is deliberate and silent; pick a
+            // different `each` name to get both setters.
+            if fn_name == setter_ident {
                 generate_all_at_once = false;
             }
-            let each_method = quote! {
-                pub fn #fn_name (&mut self, #fn_name : #field_ty) -> &mut Self {
-                        match self.#field_ident {
-                            Some(ref mut v) => {
-                                v.push(#fn_name);
-                            }
-                            None => {
-                                let mut x = Vec::new();
-                                x.push(#fn_name);
-                                self.#field_ident = Some(x);
-                            }
+            // With `into`, each element is converted before it is added.
+            let into = info.attrs.into;
+            let arg = |name: &Ident, ty: &Type| {
+                if into {
+                    (
+                        quote! { impl std::convert::Into<#ty> },
+                        quote! { std::convert::Into::into(#name) },
+                    )
+                } else {
+                    (quote! { #ty }, quote! { #name })
+                }
+            };
+            let each_method = match &field_speciality {
+                SpecialFieldTypes::Map(key_ty, _) => {
+                    let (key_arg, key) = arg(&format_ident!("key"), key_ty);
+                    let (value_arg, value) = arg(&format_ident!("value"), &field_ty);
+                    quote! {
+                        #(#attrs)*
+                        #inline
+                        #must_use
+                        #method_vis fn #fn_name (#setter_self, key: #key_arg, value: #value_arg) -> #setter_ret {
+                            self.#field_member
+                                .get_or_insert_with(std::default::Default::default)
+                                .insert(#key, #value);
+                            self
                         }
+                    }
+                }
+                SpecialFieldTypes::Set(_) => {
+                    let (elem_arg, elem) = arg(&fn_name, &field_ty);
+                    quote! {
+                        #(#attrs)*
+                        #inline
+                        #must_use
+                        #method_vis fn #fn_name (#setter_self, #fn_name : #elem_arg) -> #setter_ret {
+                            self.#field_member
+                                .get_or_insert_with(std::default::Default::default)
+                                .insert(#elem);
+                            self
+                        }
+                    }
+                }
+                SpecialFieldTypes::Extend(collection_ty) => quote! {
+                    #(#attrs)*
+                    #inline
+                    #must_use
+                    #method_vis fn #fn_name<__Item>(#setter_self, #fn_name: __Item) -> #setter_ret
+                    where
+                        #collection_ty: std::iter::Extend<__Item>,
+                    {
+                        std::iter::Extend::extend(
+                            self.#field_member.get_or_insert_with(std::default::Default::default),
+                            std::iter::once(#fn_name),
+                        );
                         self
+                    }
+                },
+                SpecialFieldTypes::Deque(_) | SpecialFieldTypes::List(_) => {
+                    let (elem_arg, elem) = arg(&fn_name, &field_ty);
+                    let new = if let SpecialFieldTypes::List(_) = field_speciality {
+                        quote! { std::collections::LinkedList::new }
+                    } else {
+                        quote! { std::collections::VecDeque::new }
+                    };
+                    quote! {
+                        #(#attrs)*
+                        #inline
+                        #must_use
+                        #method_vis fn #fn_name (#setter_self, #fn_name : #elem_arg) -> #setter_ret {
+                            self.#field_member
+                                .get_or_insert_with(#new)
+                                .push_back(#elem);
+                            self
+                        }
+                    }
+                }
+                _ => {
+                    let (elem_arg, elem) = arg(&fn_name, &field_ty);
+                    quote! {
+                        #(#attrs)*
+                        #inline
+                        #must_use
+                        #method_vis fn #fn_name (#setter_self, #fn_name : #elem_arg) -> #setter_ret {
+                            self.#field_member
+                                .get_or_insert_with(std::vec::Vec::new)
+                                .push(#elem);
+                            self
+                        }
+                    }
                 }
             };
             builder_methods.extend(each_method);
         }
-        let method = if generate_all_at_once {
-            let arg_ty = if matches!(field_speciality, SpecialFieldTypes::Vec) {
-                parse_quote! { std::vec::Vec<#field_ty> }
-            } else {
-                field_ty
-            };
+        let method = if info.attrs.nested {
+            let nested_builder = nested_builder_ty(&field_ty).unwrap();
             quote! {
-                pub fn #field_ident (&mut self, #field_ident : #arg_ty) -> &mut Self {
-                        self.#field_ident = std::option::Option::Some(#field_ident);
-                        self
+                #(#attrs)*
+                #inline
+                #method_vis fn #setter_ident(
+                    #setter_self,
+                    f: impl std::ops::FnOnce(&mut #nested_builder) -> &mut #nested_builder,
+                ) -> std::result::Result<#field_ret, std::boxed::Box<dyn std::error::Error>> {
+                    #set_once_check
+                    let mut builder = <#field_ty>::builder();
+                    f(&mut builder);
+                    self.#field_member = std::option::Option::Some(builder.build()?);
+                    std::result::Result::Ok(#field_done)
+                }
+            }
+        } else if generate_all_at_once {
+            let arg_ty = match field_speciality {
+                SpecialFieldTypes::Vec | SpecialFieldTypes::OptionVec => {
+                    parse_quote! { std::vec::Vec<#field_ty> }
+                }
+                SpecialFieldTypes::Map(_, collection_ty)
+                | SpecialFieldTypes::Set(collection_ty)
+                | SpecialFieldTypes::Deque(collection_ty)
+                | SpecialFieldTypes::List(collection_ty)
+                | SpecialFieldTypes::Extend(collection_ty) => *collection_ty,
+                _ => field_ty,
+            };
+            if info.attrs.try_into {
+                // The conversion error is only nameable through a generic
+                // parameter, not through `impl TryInto`.
+                quote! {
+                    #(#attrs)*
+                    #inline
+                    #method_vis fn #setter_ident<__Value: std::convert::TryInto<#arg_ty>>(
+                        #setter_self,
+                        #setter_ident: __Value,
+                    ) -> std::result::Result<#field_ret, __Value::Error> {
+                        #set_once_check
+                        self.#field_member = std::option::Option::Some(
+                            std::convert::TryInto::try_into(#setter_ident)?,
+                        );
+                        std::result::Result::Ok(#field_done)
+                    }
+                }
+            } else {
+                let (arg_ty, arg_value) = if info.attrs.into {
+                    (
+                        quote! { impl std::convert::Into<#arg_ty> },
+                        quote! { std::convert::Into::into(#setter_ident) },
+                    )
+                } else {
+                    (quote! { #arg_ty }, quote! { #setter_ident })
+                };
+                if info.attrs.validate.is_some() || info.attrs.set_once == Some(SetOnce::Error) {
+                    // A rejected value leaves the field as it was.
+                    let validate = info.attrs.validate.as_ref().map(|validate| {
+                        quote! { #validate(&#setter_ident)?; }
+                    });
+                    quote! {
+                        #(#attrs)*
+                        #inline
+                        #method_vis fn #setter_ident(
+                            #setter_self,
+                            #setter_ident: #arg_ty,
+                        ) -> std::result::Result<#field_ret, std::boxed::Box<dyn std::error::Error>> {
+                            #set_once_check
+                            let #setter_ident = #arg_value;
+                            #validate
+                            self.#field_member = std::option::Option::Some(#setter_ident);
+                            std::result::Result::Ok(#field_done)
+                        }
+                    }
+                } else {
+                    quote! {
+                        #(#attrs)*
+                        #inline
+                        #must_use
+                        #method_vis fn #setter_ident (#setter_self, #setter_ident : #arg_ty) -> #field_ret {
+                                #set_once_check
+                                self.#field_member = std::option::Option::Some(#arg_value);
+                                #field_done
+                        }
+                    }
                 }
             }
         } else {
             quote! {}
         };
         builder_methods.extend(method);
+
+        // Set the field only if there is a value, through the setter above.
+        if info.attrs.opt_setter {
+            // Whether the field is set would decide the builder's type.
+            if required[field_idx] {
+                return syn::Error::new(
+                    setter_ident.span(),
+                    "`opt_setter` can't be used on a required field of a `typestate` builder",
+                )
+                .to_compile_error();
+            }
+            let opt_ident = format_ident!("{}_opt", setter_ident.unraw());
+            let elem_ty = &info.ty;
+            let value_ty = if info.attrs.into {
+                quote! { impl std::convert::Into<#elem_ty> }
+            } else {
+                quote! { #elem_ty }
+            };
+            builder_methods.extend(quote! {
+                #(#attrs)*
+                #inline
+                #must_use
+                #method_vis fn #opt_ident(
+                    #setter_self,
+                    value: std::option::Option<#value_ty>,
+                ) -> #setter_ret {
+                    match value {
+                        std::option::Option::Some(value) => self.#setter_ident(value),
+                        std::option::Option::None => self,
+                    }
+                }
+            });
+        }
+
+        // Add many elements at once to a collection, on top of whatever the
+        // other setters put there. A collection guarded by `validate` or
+        // `set_once` only changes through its all-at-once setter.
+        let elem_ty = &info.ty;
+        let item_ty = match &info.speciality {
+            SpecialFieldTypes::Vec
+            | SpecialFieldTypes::OptionVec
+            | SpecialFieldTypes::Set(_)
+            | SpecialFieldTypes::Deque(_)
+            | SpecialFieldTypes::List(_) => Some(quote! { #elem_ty }),
+            SpecialFieldTypes::Map(key_ty, _) => Some(quote! { (#key_ty, #elem_ty) }),
+            SpecialFieldTypes::Extend(_) => Some(quote! { __Item }),
+            _ => None,
+        };
+        if let Some(item_ty) = item_ty.filter(|_| has_extend_setter(info)) {
+            let extend_ident = format_ident!("{}_extend", setter_ident.unraw());
+            let (item_param, item_bound) = match &info.speciality {
+                SpecialFieldTypes::Extend(collection_ty) => (
+                    quote! { <__Item> },
+                    quote! { where #collection_ty: std::iter::Extend<__Item> },
+                ),
+                _ => (quote! {}, quote! {}),
+            };
+            builder_methods.extend(quote! {
+                #(#attrs)*
+                #inline
+                #must_use
+                #method_vis fn #extend_ident #item_param (
+                    #setter_self,
+                    iter: impl std::iter::IntoIterator<Item = #item_ty>,
+                ) -> #setter_ret
+                #item_bound
+                {
+                    std::iter::Extend::extend(
+                        self.#field_member.get_or_insert_with(std::default::Default::default),
+                        iter,
+                    );
+                    self
+                }
+            });
+        }
+
+        // Whether the field was given a value, by any of its setters.
+        let cfgs = &info.cfgs;
+        let lints = &info.lints;
+        let is_set_ident = format_ident!("is_{}_set", setter_ident.unraw());
+        builder_methods.extend(quote! {
+            #( #cfgs )*
+            #( #lints )*
+            #method_vis fn #is_set_ident(&self) -> bool {
+                self.#field_member.is_some()
+            }
+        });
+        // Back to unset, as if no setter had been called.
+        if struct_attrs.clearable {
+            let clear_ident = format_ident!("clear_{}", setter_ident.unraw());
+            let (clear_ret, clear_done) = field_state(&unset_ident);
+            builder_methods.extend(quote! {
+                #( #cfgs )*
+                #( #lints )*
+                #inline
+                #must_use
+                #method_vis fn #clear_ident(#setter_self) -> #clear_ret {
+                    self.#field_member = std::option::Option::None;
+                    #clear_done
+                }
+            });
+        }
     }
 
     let mut uninit_checks = quote! {};
     let mut field_assigns = quote! {};
-    for field_idx in 0..field_idents.len() {
-        let field_ident = field_idents[field_idx].clone();
-        let (_field_ty, field_specialty) = field_tys[field_idx].clone();
-        let (check, assign) = match field_specialty {
-            SpecialFieldTypes::Option => (
+    // The same assignments for `build_unchecked()`, which trusts that every
+    // required field is set.
+    let mut unchecked_assigns = quote! {};
+    // One error variant (and its field name) per required field.
+    let mut error_variants = vec![];
+    let mut error_names = vec![];
+    let mut error_fields: Vec<Ident> = vec![];
+    let mut error_cfgs = vec![];
+    for info in &field_infos {
+        let field_member = info.member.clone();
+        let field_ident = info.ident.clone();
+        let field_specialty = info.speciality.clone();
+        let cfgs = &info.cfgs;
+        // Whichever assignment follows is gated like the field.
+        field_assigns.extend(quote! { #( #cfgs )* });
+        unchecked_assigns.extend(quote! { #( #cfgs )* });
+        // Move the value out of the builder, leaving `None` behind unless the
+        // builder itself is consumed.
+        let take = if struct_attrs.build_by_value {
+            quote! { self.#field_member }
+        } else {
+            quote! { self.#field_member.take() }
+        };
+        if info.attrs.skip {
+            let assign = quote! {
+                #field_member: std::default::Default::default(),
+            };
+            field_assigns.extend(assign.clone());
+            unchecked_assigns.extend(assign);
+            continue;
+        }
+        if let Some(default) = &info.attrs.default {
+            // The default is only evaluated when the field was never set.
+            let assign = match field_specialty {
+                SpecialFieldTypes::Option | SpecialFieldTypes::OptionVec => quote! {
+                    #field_member: #take.or_else(|| #default),
+                },
+                SpecialFieldTypes::Vec
+                | SpecialFieldTypes::Map(..)
+                | SpecialFieldTypes::Set(..)
+                | SpecialFieldTypes::Deque(..)
+                | SpecialFieldTypes::List(..)
+                | SpecialFieldTypes::Extend(..)
+                | SpecialFieldTypes::Unknown => {
+                    quote! {
+                        #field_member: #take.unwrap_or_else(|| #default),
+                    }
+                }
+                SpecialFieldTypes::Wrapper(wrapper) => quote! {
+                    #field_member: #take.map(#wrapper::new).unwrap_or_else(|| #default),
+                },
+                SpecialFieldTypes::OptionWrapper(wrapper) => quote! {
+                    #field_member: #take.map(#wrapper::new).or_else(|| #default),
+                },
+            };
+            field_assigns.extend(assign.clone());
+            unchecked_assigns.extend(assign);
+            continue;
+        }
+        let (check, assign, unchecked_assign) = match field_specialty {
+            SpecialFieldTypes::Option | SpecialFieldTypes::OptionVec => (
+                quote! {},
+                quote! {
+                    #field_member: #take,
+                },
+                quote! {},
+            ),
+            SpecialFieldTypes::OptionWrapper(wrapper) => (
                 quote! {},
                 quote! {
-                    #field_ident: std::mem::replace(&mut self.#field_ident, std::option::Option::None),
+                    #field_member: #take.map(#wrapper::new),
                 },
+                quote! {},
             ),
             SpecialFieldTypes::Vec => (
                 quote! {},
                 quote! {
-                    #field_ident: std::mem::replace(&mut self.#field_ident, std::option::Option::None).unwrap_or(Vec::new()),
+                    #field_member: #take.unwrap_or(Vec::new()),
                 },
+                quote! {},
             ),
-            SpecialFieldTypes::Unknown => (
+            // Default rather than `HashMap::new()`, so that maps and sets
+            // with a custom hasher work too, as do `extend` collections.
+            SpecialFieldTypes::Map(..)
+            | SpecialFieldTypes::Set(..)
+            | SpecialFieldTypes::Extend(..) => (
+                quote! {},
                 quote! {
-                    if self.#field_ident.is_none() {
-                        return Err(format!("Field {} not initialized", stringify!(#field_ident)).into());
-                    }
+                    #field_member: #take.unwrap_or_default(),
                 },
+                quote! {},
+            ),
+            SpecialFieldTypes::Deque(_) => (
+                quote! {},
                 quote! {
-                    #field_ident: std::mem::replace(&mut self.#field_ident, std::option::Option::None).unwrap(),
+                    #field_member: #take.unwrap_or_else(std::collections::VecDeque::new),
                 },
+                quote! {},
+            ),
+            SpecialFieldTypes::List(_) => (
+                quote! {},
+                quote! {
+                    #field_member: #take.unwrap_or_else(std::collections::LinkedList::new),
+                },
+                quote! {},
             ),
+            SpecialFieldTypes::Wrapper(_) | SpecialFieldTypes::Unknown => {
+                // Report the field by the setter the caller would use to fix
+                // it, which differs from the field name after `rename`.
+                let field_name = info
+                    .attrs
+                    .rename
+                    .as_ref()
+                    .unwrap_or(&field_ident)
+                    .unraw()
+                    .to_string();
+                let missing = if struct_attrs.boxed_error || struct_attrs.typestate {
+                    quote! { #field_name }
+                } else {
+                    let variant = format_ident!("Missing{}", upper_camel_case(&field_ident));
+                    // `addr_1` and `addr1` both camel-case to `Addr1`.
+                    if let Some(other) = error_variants
+                        .iter()
+                        .position(|other| *other == variant)
+                        .map(|i| &error_fields[i])
+                    {
+                        return syn::Error::new(
+                            field_ident.span(),
+                            format!(
+                                "the error variant `{}` would be generated both for `{}` and for `{}`",
+                                variant,
+                                Ident::unraw(other),
+                                field_ident.unraw()
+                            ),
+                        )
+                        .to_compile_error();
+                    }
+                    error_variants.push(variant.clone());
+                    error_fields.push(field_ident.clone());
+                    error_names.push(field_name);
+                    error_cfgs.push(cfgs.clone());
+                    quote! { #error_ident::#variant }
+                };
+                let (value, unchecked_value) = match field_specialty {
+                    SpecialFieldTypes::Wrapper(wrapper) => (
+                        quote! { #wrapper::new(#take.unwrap()) },
+                        quote! { #wrapper::new(#take.unwrap_unchecked()) },
+                    ),
+                    _ => (
+                        quote! { #take.unwrap() },
+                        quote! { #take.unwrap_unchecked() },
+                    ),
+                };
+                // A typestate builder can't reach `build()` with the field
+                // unset.
+                let check = if struct_attrs.typestate {
+                    quote! {}
+                } else {
+                    quote! {
+                        #( #cfgs )*
+                        if self.#field_member.is_none() {
+                            missing.push(#missing);
+                        }
+                    }
+                };
+                (
+                    check,
+                    quote! {
+                        #field_member: #value,
+                    },
+                    quote! {
+                        #field_member: #unchecked_value,
+                    },
+                )
+            }
         };
         uninit_checks.extend(check);
+        // Only required fields differ between the two.
+        if unchecked_assign.is_empty() {
+            unchecked_assigns.extend(assign.clone());
+        } else {
+            unchecked_assigns.extend(unchecked_assign);
+        }
         field_assigns.extend(assign);
     }
-    let build_method = quote! {
-        pub fn build(&mut self) -> std::result::Result<#name, std::boxed::Box<dyn std::error::Error>> {
-            #uninit_checks
-            Ok(#name {
-                #field_assigns
-            })
+
+    // Collect every missing field before reporting, rather than stopping
+    // at the first one. Messages name the struct, so that errors from
+    // several builders can be told apart.
+    let build_path = format!("{}::{}", name.unraw(), build_ident.unraw());
+    if !uninit_checks.is_empty() {
+        let consumed_error = if struct_attrs.boxed_error {
+            quote! { format!("{}: builder already consumed", #build_path).into() }
+        } else {
+            quote! { #error_ident::Consumed }
+        };
+        // Only while nothing was set since, so a builder that was filled in
+        // again still reports what it's missing.
+        let consumed_check = consumed_member.as_ref().map(|member| {
+            quote! {
+                let mut untouched = self.#member;
+                #( #( #field_cfgs )* { untouched &= self.#field_members.is_none(); } )*
+                if untouched {
+                    return std::result::Result::Err(#consumed_error);
+                }
+            }
+        });
+        uninit_checks = if struct_attrs.boxed_error {
+            quote! {
+                #consumed_check
+                let mut missing: std::vec::Vec<&'static str> = std::vec::Vec::new();
+                #uninit_checks
+                match missing.len() {
+                    0 => {}
+                    1 => return std::result::Result::Err(format!("{}: field `{}` not initialized", #build_path, missing[0]).into()),
+                    _ => {
+                        let names: std::vec::Vec<_> = missing.iter().map(|name| format!("`{}`", name)).collect();
+                        return std::result::Result::Err(format!("{}: fields {} not initialized", #build_path, names.join(", ")).into());
+                    }
+                }
+            }
+        } else {
+            quote! {
+                #consumed_check
+                let mut missing: std::vec::Vec<#error_ident> = std::vec::Vec::new();
+                #uninit_checks
+                if missing.len() > 1 {
+                    return std::result::Result::Err(#error_ident::Multiple(missing));
+                }
+                if let std::option::Option::Some(error) = missing.pop() {
+                    return std::result::Result::Err(error);
+                }
+            }
+        };
+    }
+
+    // The validation error goes through the same error type as a missing
+    // field, boxed either way.
+    let (validation, validation_variant) = match &struct_attrs.validate {
+        Some(validate) if struct_attrs.boxed_error => (quote! { #validate(&value)?; }, quote! {}),
+        Some(validate) => (
+            quote! {
+                #validate(&value).map_err(|error| #error_ident::Validation(error.into()))?;
+            },
+            quote! {
+                // Rejected by the `validate` function.
+                Validation(std::boxed::Box<dyn std::error::Error>),
+            },
+        ),
+        None => (quote! {}, quote! {}),
+    };
+    let validation_display = if validation_variant.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            #error_ident::Validation(error) => write!(f, "{}", error),
         }
     };
-    let builder_methods = quote! {
-        impl #builder_ident {
-            #builder_methods
-            #build_method
+
+    let (consumed_variant, consumed_display) = if consumed_member.is_some() {
+        (
+            quote! {
+                // `build()` already took the fields, and none were set since.
+                Consumed,
+            },
+            quote! {
+                #error_ident::Consumed => write!(f, "{}: builder already consumed", #build_path),
+            },
+        )
+    } else {
+        (quote! {}, quote! {})
+    };
+    let (error_ty, error_enum) = if struct_attrs.boxed_error
+        || struct_attrs.typestate
+        || struct_attrs.default
+    {
+        (quote! { std::boxed::Box<dyn std::error::Error> }, quote! {})
+    } else {
+        (
+            quote! { #error_ident },
+            quote! {
+                #[derive(Debug)]
+                #vis enum #error_ident {
+                    #( #( #error_cfgs )* #error_variants, )*
+                    #validation_variant
+                    #consumed_variant
+                    // More than one missing field.
+                    Multiple(std::vec::Vec<#error_ident>),
+                }
+
+                impl std::fmt::Display for #error_ident {
+                    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        let name = |error: &#error_ident| match error {
+                            #( #( #error_cfgs )* #error_ident::#error_variants => #error_names, )*
+                            _ => "",
+                        };
+                        match self {
+                            #error_ident::Multiple(errors) => {
+                                let names: std::vec::Vec<_> = errors.iter().map(|error| format!("`{}`", name(error))).collect();
+                                write!(f, "{}: fields {} not initialized", #build_path, names.join(", "))
+                            }
+                            #validation_display
+                            #consumed_display
+                            error => write!(f, "{}: field `{}` not initialized", #build_path, name(error)),
+                        }
+                    }
+                }
+
+                impl std::error::Error for #error_ident {}
+            },
+        )
+    };
+    let build_self = if struct_attrs.build_by_value {
+        quote! { self }
+    } else {
+        quote! { &mut self }
+    };
+    let consumed_set = consumed_member
+        .as_ref()
+        .map(|member| quote! { self.#member = true; });
+    let build_unchecked_method = if struct_attrs.unchecked {
+        quote! {
+            /// Like `build()`, but without checking that the required fields
+            /// are set, and without running `validate`.
+            ///
+            /// # Safety
+            ///
+            /// Every field that `build()` would report as not initialized must
+            /// have been set.
+            #method_vis unsafe fn build_unchecked(#build_self) -> #name #ty_generics {
+                let value = #name {
+                    #unchecked_assigns
+                };
+                #consumed_set
+                value
+            }
+        }
+    } else {
+        quote! {}
+    };
+    let build_method = if struct_attrs.default {
+        // Only fields that were set override the struct's default. A field
+        // with its own default still falls back to that instead.
+        let overrides = field_infos
+            .iter()
+            .filter(|info| !info.attrs.skip)
+            .map(|info| {
+                let field_member = &info.member;
+                let cfgs = &info.cfgs;
+                let take = if struct_attrs.build_by_value {
+                    quote! { self.#field_member }
+                } else {
+                    quote! { self.#field_member.take() }
+                };
+                let set_value = match &info.speciality {
+                    SpecialFieldTypes::Option | SpecialFieldTypes::OptionVec => {
+                        quote! { std::option::Option::Some(set) }
+                    }
+                    SpecialFieldTypes::Wrapper(wrapper) => quote! { #wrapper::new(set) },
+                    SpecialFieldTypes::OptionWrapper(wrapper) => {
+                        quote! { std::option::Option::Some(#wrapper::new(set)) }
+                    }
+                    _ => quote! { set },
+                };
+                let unset = match &info.attrs.default {
+                    Some(default) => quote! { value.#field_member = #default },
+                    None => quote! {},
+                };
+                quote! {
+                    #( #cfgs )*
+                    match #take {
+                        std::option::Option::Some(set) => value.#field_member = #set_value,
+                        std::option::Option::None => { #unset }
+                    }
+                }
+            });
+        quote! {
+            #method_vis fn #build_ident(#build_self) -> #name #ty_generics {
+                let mut value: #name #ty_generics = std::default::Default::default();
+                #( #overrides )*
+                value
+            }
+        }
+    } else if struct_attrs.typestate {
+        quote! {
+            #method_vis fn #build_ident(#build_self) -> #name #ty_generics {
+                #name {
+                    #field_assigns
+                }
+            }
+        }
+    } else {
+        quote! {
+            #method_vis fn #build_ident(#build_self) -> std::result::Result<#name #ty_generics, #error_ty> {
+                #uninit_checks
+                let value = #name {
+                    #field_assigns
+                };
+                #consumed_set
+                #validation
+                std::result::Result::Ok(value)
+            }
+        }
+    };
+    // `build()` on a copy of the builder. Only the fields are copied, so the
+    // builder itself doesn't need to be `Clone`, and a skipped field is never
+    // set anyway.
+    let build_clone_method = if struct_attrs.clone_build {
+        let build_ret = if struct_attrs.default || struct_attrs.typestate {
+            quote! { #name #ty_generics }
+        } else {
+            quote! { std::result::Result<#name #ty_generics, #error_ty> }
+        };
+        let copy_mut = if struct_attrs.build_by_value {
+            quote! {}
+        } else {
+            quote! { mut }
+        };
+        let copies = field_infos.iter().map(|info| {
+            let field_member = &info.member;
+            let cfgs = &info.cfgs;
+            if info.attrs.skip {
+                quote! { #( #cfgs )* #field_member: std::option::Option::None, }
+            } else {
+                quote! { #( #cfgs )* #field_member: std::clone::Clone::clone(&self.#field_member), }
+            }
+        });
+        quote! {
+            #method_vis fn build_clone(&self) -> #build_ret {
+                let #copy_mut copy = #builder_ident {
+                    #( #copies )*
+                    #typestate_init
+                    #consumed_init
+                };
+                copy.#build_ident()
+            }
+        }
+    } else {
+        quote! {}
+    };
+    // `build()`, moved onto the heap.
+    let build_boxed_method = if !struct_attrs.boxed {
+        quote! {}
+    } else if struct_attrs.default || struct_attrs.typestate {
+        quote! {
+            #method_vis fn build_boxed(#build_self) -> std::boxed::Box<#name #ty_generics> {
+                std::boxed::Box::new(self.#build_ident())
+            }
+        }
+    } else {
+        quote! {
+            #method_vis fn build_boxed(#build_self) -> std::result::Result<std::boxed::Box<#name #ty_generics>, #error_ty> {
+                self.#build_ident().map(std::boxed::Box::new)
+            }
+        }
+    };
+    // Runs a closure on the builder without breaking a chain of setters, so
+    // that some of them can be called conditionally. Owned setters need the
+    // builder itself, so the closure takes and returns it.
+    let with_method = if struct_attrs.owned {
+        quote! {
+            #must_use
+            #method_vis fn with(self, f: impl std::ops::FnOnce(Self) -> Self) -> #setter_ret {
+                f(self)
+            }
+        }
+    } else {
+        quote! {
+            #must_use
+            #method_vis fn with(&mut self, f: impl std::ops::FnOnce(&mut Self)) -> &mut Self {
+                f(self);
+                self
+            }
+        }
+    };
+    // Takes what another builder has set: a collection gets the other's
+    // elements added, any other field the other's value if it has none yet,
+    // or, with `merge = "override"`, whenever the other has one. An `extend`
+    // collection counts as any other field, since it need not iterate over
+    // what it can be extended with, the way a `String` doesn't. A typestate
+    // builder has none, as its type can't depend on what the other has set.
+    let merge_method = if !struct_attrs.typestate {
+        let merge = struct_attrs.merge.unwrap_or(Merge::Fill);
+        let merges = field_infos
+            .iter()
+            .filter(|info| !info.attrs.skip)
+            .map(|info| {
+                let field_member = &info.member;
+                let cfgs = &info.cfgs;
+                let merge = match info.speciality {
+                    SpecialFieldTypes::Vec
+                    | SpecialFieldTypes::OptionVec
+                    | SpecialFieldTypes::Map(..)
+                    | SpecialFieldTypes::Set(_)
+                    | SpecialFieldTypes::Deque(_)
+                    | SpecialFieldTypes::List(_) => quote! {
+                        if let std::option::Option::Some(more) = other.#field_member {
+                            match self.#field_member.as_mut() {
+                                std::option::Option::Some(existing) => {
+                                    std::iter::Extend::extend(existing, more)
+                                }
+                                std::option::Option::None => {
+                                    self.#field_member = std::option::Option::Some(more)
+                                }
+                            }
+                        }
+                    },
+                    _ if merge == Merge::Override => quote! {
+                        if other.#field_member.is_some() {
+                            self.#field_member = other.#field_member;
+                        }
+                    },
+                    _ => quote! {
+                        if self.#field_member.is_none() {
+                            self.#field_member = other.#field_member;
+                        }
+                    },
+                };
+                quote! { #( #cfgs )* { #merge } }
+            });
+        quote! {
+            #must_use
+            #method_vis fn merge(#setter_self, other: Self) -> #setter_ret {
+                #( #merges )*
+                self
+            }
+        }
+    } else {
+        quote! {}
+    };
+    // Clears every field, the same state `builder()` starts from.
+    let (reset_ret, reset_done) = if struct_attrs.typestate {
+        (constructor_ret.clone(), transition)
+    } else {
+        (setter_ret, quote! { self })
+    };
+    let consumed_reset = consumed_member
+        .as_ref()
+        .map(|member| quote! { self.#member = false; });
+    let reset_method = quote! {
+        #must_use
+        #method_vis fn reset(#setter_self) -> #reset_ret {
+            // In a block because an assignment can't have a `cfg` itself.
+            #( #( #field_cfgs )* { self.#field_members = std::option::Option::None; } )*
+            #consumed_reset
+            #reset_done
+        }
+    };
+    let builder_methods = if struct_attrs.typestate {
+        let all_set = vec![&set_ident; state_params.len()];
+        let built_ty = builder_ty_with(&all_set);
+        quote! {
+            impl #state_impl_generics #builder_ident #state_ty_generics #where_clause {
+                #builder_methods
+                #with_method
+                #reset_method
+            }
+
+            impl #impl_generics #built_ty #where_clause {
+                #build_method
+                #build_boxed_method
+                #build_clone_method
+            }
+
+            // The states of a required field in the builder's type.
+            #[derive(Debug, Clone, Copy)]
+            #vis struct #set_ident;
+            #[derive(Debug, Clone, Copy)]
+            #vis struct #unset_ident;
+        }
+    } else {
+        quote! {
+            impl #impl_generics #builder_ident #ty_generics #where_clause {
+                #builder_methods
+                #with_method
+                #merge_method
+                #reset_method
+                #build_method
+                #build_boxed_method
+                #build_unchecked_method
+                #build_clone_method
+            }
+        }
+    };
+    // The same conversion as `build()`, for code written against `TryFrom`.
+    // Builders whose `build()` can't fail have nothing to convert with.
+    let try_from_impl = if struct_attrs.typestate || struct_attrs.default {
+        quote! {}
+    } else {
+        let builder_arg = if struct_attrs.build_by_value {
+            quote! { builder }
+        } else {
+            quote! { mut builder }
+        };
+        quote! {
+            impl #impl_generics std::convert::TryFrom<#builder_ident #ty_generics> for #name #ty_generics #where_clause {
+                type Error = #error_ty;
+
+                fn try_from(#builder_arg: #builder_ident #ty_generics) -> std::result::Result<Self, Self::Error> {
+                    builder.#build_ident()
+                }
+            }
         }
     };
 
     output.extend(builder_struct.to_token_stream());
     output.extend(builder_fn.into_token_stream());
     output.extend(builder_methods.to_token_stream());
-    output.into()
+    output.extend(try_from_impl);
+    output.extend(debug_impl);
+    output.extend(error_enum);
+
+    // A `cfg` can only gate one item at a time, so it goes on each of them.
+    if let Some(cfg) = &struct_attrs.cfg {
+        let mut file: syn::File = syn::parse2(output).unwrap();
+        for item in &mut file.items {
+            let attrs = match item {
+                Item::Struct(item) => &mut item.attrs,
+                Item::Enum(item) => &mut item.attrs,
+                Item::Impl(item) => &mut item.attrs,
+                _ => unreachable!("only structs, enums and impls are generated"),
+            };
+            attrs.insert(0, parse_quote! { #[cfg(#cfg)] });
+        }
+        return file.into_token_stream();
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proc_macro2::TokenTree;
+
+    // The names of all functions in the generated code, in order.
+    fn fn_names(tokens: proc_macro2::TokenStream, names: &mut Vec<String>) {
+        let mut after_fn = false;
+        for token in tokens {
+            match &token {
+                TokenTree::Ident(ident) if after_fn => names.push(ident.to_string()),
+                TokenTree::Group(group) => fn_names(group.stream(), names),
+                _ => {}
+            }
+            after_fn = matches!(&token, TokenTree::Ident(ident) if ident == "fn");
+        }
+    }
+
+    // The generated code without the function bodies, one line per struct,
+    // enum, impl header and function signature with its attributes.
+    fn signatures(tokens: proc_macro2::TokenStream) -> Vec<String> {
+        let file: syn::File = syn::parse2(tokens).unwrap();
+        let mut lines = vec![];
+        for item in file.items {
+            if let Item::Struct(_) | Item::Enum(_) = item {
+                lines.push(item.to_token_stream().to_string());
+            } else if let Item::Impl(item) = item {
+                let self_ty = &item.self_ty;
+                lines.push(
+                    match &item.trait_ {
+                        Some((_, trait_path, _)) => quote! { impl #trait_path for #self_ty },
+                        None => quote! { impl #self_ty },
+                    }
+                    .to_string(),
+                );
+                for impl_item in item.items {
+                    if let syn::ImplItem::Fn(method) = impl_item {
+                        let attrs = &method.attrs;
+                        let vis = &method.vis;
+                        let sig = &method.sig;
+                        lines.push(quote! { #( #attrs )* #vis #sig }.to_string());
+                    }
+                }
+            }
+        }
+        lines
+    }
+
+    // Every function in the generated impls, in order.
+    fn impl_fns(tokens: proc_macro2::TokenStream) -> Vec<syn::ImplItemFn> {
+        let file: syn::File = syn::parse2(tokens).unwrap();
+        let mut fns = vec![];
+        for item in file.items {
+            if let Item::Impl(item) = item {
+                for impl_item in item.items {
+                    if let syn::ImplItem::Fn(method) = impl_item {
+                        fns.push(method);
+                    }
+                }
+            }
+        }
+        fns
+    }
+
+    #[test]
+    fn setter_docs() {
+        let input: DeriveInput = parse_quote! {
+            pub struct Command {
+                /// The program to run.
+                executable: String,
+                #[doc = "Arguments passed to the program."]
+                #[builder(each = "arg")]
+                args: Vec<String>,
+                current_dir: Option<String>,
+            }
+        };
+        let docs = |method: &syn::ImplItemFn| -> Vec<String> {
+            method
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("doc"))
+                .map(|attr| attr.to_token_stream().to_string())
+                .collect()
+        };
+        let program = [r#"# [doc = r" The program to run."]"#];
+        let arguments = [r#"# [doc = "Arguments passed to the program."]"#];
+        for method in impl_fns(expand(input)) {
+            match method.sig.ident.to_string().as_str() {
+                "executable" => assert_eq!(docs(&method), program),
+                "arg" | "args" | "args_extend" => assert_eq!(docs(&method), arguments),
+                _ => assert!(docs(&method).is_empty(), "{}", method.sig.ident),
+            }
+            assert!(!method
+                .attrs
+                .iter()
+                .any(|attr| attr.path().is_ident("builder")));
+        }
+    }
+
+    #[test]
+    fn inline_setters() {
+        let input: DeriveInput = parse_quote! {
+            #[builder(inline, clearable)]
+            pub struct Command {
+                executable: String,
+                #[builder(each = "arg", into)]
+                args: Vec<String>,
+                #[builder(opt_setter)]
+                current_dir: Option<String>,
+            }
+        };
+        let inlined: Vec<String> = impl_fns(expand(input))
+            .into_iter()
+            .filter(|method| {
+                method
+                    .attrs
+                    .iter()
+                    .any(|attr| attr.path().is_ident("inline"))
+            })
+            .map(|method| method.sig.ident.to_string())
+            .collect();
+        assert_eq!(
+            inlined,
+            [
+                "builder",
+                "executable",
+                "clear_executable",
+                "arg",
+                "args",
+                "args_extend",
+                "clear_args",
+                "current_dir",
+                "current_dir_opt",
+                "clear_current_dir",
+            ],
+        );
+
+        let input: DeriveInput = parse_quote! {
+            pub struct Command {
+                executable: String,
+            }
+        };
+        assert!(impl_fns(expand(input)).iter().all(|method| !method
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident("inline"))));
+    }
+
+    // A snapshot of everything but the bodies, so that a change to a type,
+    // an attribute or a visibility doesn't go unnoticed either.
+    #[test]
+    fn expansion_signatures() {
+        let input: DeriveInput = parse_quote! {
+            pub struct Command {
+                executable: String,
+                #[builder(each = "arg")]
+                args: Vec<String>,
+                current_dir: Option<String>,
+            }
+        };
+        assert_eq!(
+            signatures(expand(input)),
+            [
+                "pub struct CommandBuilder { executable : std :: option :: Option < String > , args : std :: option :: Option < Vec < String > > , current_dir : Option < String > , __consumed : bool }",
+                "impl Command",
+                "pub fn builder () -> CommandBuilder",
+                "impl std :: default :: Default for CommandBuilder",
+                "fn default () -> Self",
+                "impl CommandBuilder",
+                "fn executable (& mut self , executable : String) -> & mut Self",
+                "fn is_executable_set (& self) -> bool",
+                "fn arg (& mut self , arg : String) -> & mut Self",
+                "fn args (& mut self , args : std :: vec :: Vec < String >) -> & mut Self",
+                "fn args_extend (& mut self , iter : impl std :: iter :: IntoIterator < Item = String > ,) -> & mut Self",
+                "fn is_args_set (& self) -> bool",
+                "fn current_dir (& mut self , current_dir : String) -> & mut Self",
+                "fn is_current_dir_set (& self) -> bool",
+                "pub fn with (& mut self , f : impl std :: ops :: FnOnce (& mut Self)) -> & mut Self",
+                "pub fn merge (& mut self , other : Self) -> & mut Self",
+                "pub fn reset (& mut self) -> & mut Self",
+                "pub fn build (& mut self) -> std :: result :: Result < Command , CommandBuilderError >",
+                "impl std :: convert :: TryFrom < CommandBuilder > for Command",
+                "fn try_from (mut builder : CommandBuilder) -> std :: result :: Result < Self , Self :: Error >",
+                "# [derive (Debug)] pub enum CommandBuilderError { MissingExecutable , Consumed , Multiple (std :: vec :: Vec < CommandBuilderError >) , }",
+                "impl std :: fmt :: Display for CommandBuilderError",
+                "fn fmt (& self , f : & mut std :: fmt :: Formatter < '_ >) -> std :: fmt :: Result",
+                "impl std :: error :: Error for CommandBuilderError",
+            ],
+        );
+
+        // Owned setters name the builder type, generics included, rather
+        // than returning `Self`.
+        let input: DeriveInput = parse_quote! {
+            #[builder(style = "owned")]
+            pub struct Command<T> {
+                executable: String,
+                #[builder(each = "arg")]
+                args: Vec<T>,
+            }
+        };
+        assert_eq!(
+            signatures(expand(input)),
+            [
+                "pub struct CommandBuilder < T > { executable : std :: option :: Option < String > , args : std :: option :: Option < Vec < T > > , }",
+                "impl Command < T >",
+                "pub fn builder () -> CommandBuilder < T >",
+                "impl std :: default :: Default for CommandBuilder < T >",
+                "fn default () -> Self",
+                "impl CommandBuilder < T >",
+                "fn executable (mut self , executable : String) -> CommandBuilder < T >",
+                "fn is_executable_set (& self) -> bool",
+                "fn arg (mut self , arg : T) -> CommandBuilder < T >",
+                "fn args (mut self , args : std :: vec :: Vec < T >) -> CommandBuilder < T >",
+                "fn args_extend (mut self , iter : impl std :: iter :: IntoIterator < Item = T > ,) -> CommandBuilder < T >",
+                "fn is_args_set (& self) -> bool",
+                "pub fn with (self , f : impl std :: ops :: FnOnce (Self) -> Self) -> CommandBuilder < T >",
+                "pub fn merge (mut self , other : Self) -> CommandBuilder < T >",
+                "pub fn reset (mut self) -> CommandBuilder < T >",
+                "pub fn build (self) -> std :: result :: Result < Command < T > , CommandBuilderError >",
+                "impl std :: convert :: TryFrom < CommandBuilder < T > > for Command < T >",
+                "fn try_from (builder : CommandBuilder < T >) -> std :: result :: Result < Self , Self :: Error >",
+                "# [derive (Debug)] pub enum CommandBuilderError { MissingExecutable , Multiple (std :: vec :: Vec < CommandBuilderError >) , }",
+                "impl std :: fmt :: Display for CommandBuilderError",
+                "fn fmt (& self , f : & mut std :: fmt :: Formatter < '_ >) -> std :: fmt :: Result",
+                "impl std :: error :: Error for CommandBuilderError",
+            ],
+        );
+    }
+
+    #[test]
+    fn method_order() {
+        let input: DeriveInput = parse_quote! {
+            #[builder(clearable)]
+            pub struct Command {
+                executable: String,
+                #[builder(each = "arg")]
+                args: Vec<String>,
+                #[builder(opt_setter)]
+                current_dir: Option<String>,
+            }
+        };
+        let mut names = vec![];
+        fn_names(expand(input), &mut names);
+        assert_eq!(
+            names,
+            [
+                "builder",
+                "default",
+                "executable",
+                "is_executable_set",
+                "clear_executable",
+                "arg",
+                "args",
+                "args_extend",
+                "is_args_set",
+                "clear_args",
+                "current_dir",
+                "current_dir_opt",
+                "is_current_dir_set",
+                "clear_current_dir",
+                "with",
+                "merge",
+                "reset",
+                "build",
+                "try_from",
+                "fmt",
+            ],
+        );
+    }
 }
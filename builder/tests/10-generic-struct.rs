@@ -0,0 +1,25 @@
+// The builder should work for structs that are generic over a type parameter.
+// The generated builder struct carries the same generic parameters and bounds
+// as the input, and both the `builder()` constructor and the builder's own impl
+// block need the generics from `split_for_impl()`.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Wrapper<T: Clone> {
+    value: T,
+    values: Vec<T>,
+    fallback: Option<T>,
+}
+
+fn main() {
+    let wrapper = Wrapper::builder()
+        .value("first".to_owned())
+        .values(vec!["second".to_owned()])
+        .build()
+        .unwrap();
+
+    assert_eq!(wrapper.value, "first");
+    assert_eq!(wrapper.values, vec!["second"]);
+    assert_eq!(wrapper.fallback, None);
+}
@@ -0,0 +1,48 @@
+// A field marked #[builder(set_once)] can't be overwritten: calling its setter
+// a second time panics, naming the builder and the field. With
+// #[builder(set_once = "error")] the setter returns a Result instead, whose
+// error carries the same message, and the first value stays in place.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+pub struct Config {
+    #[builder(set_once)]
+    name: String,
+    #[builder(set_once = "error")]
+    threads: usize,
+    #[builder(set_once = "error", into)]
+    label: Option<String>,
+    verbose: bool,
+}
+
+fn main() {
+    let mut builder = Config::builder();
+    builder.name("app".to_owned());
+    assert!(builder.threads(4).is_ok());
+    let err = builder.threads(8).err().unwrap();
+    assert_eq!(err.to_string(), "ConfigBuilder: `threads` was already set");
+
+    assert!(builder.label("first").is_ok());
+    assert!(builder.label("second").is_err());
+
+    // Fields without `set_once` can still be set any number of times.
+    builder.verbose(false).verbose(true);
+
+    let config = builder.build().unwrap();
+    assert_eq!(config.name, "app");
+    assert_eq!(config.threads, 4);
+    assert_eq!(config.label.as_deref(), Some("first"));
+    assert!(config.verbose);
+
+    let panic = std::panic::catch_unwind(|| {
+        Config::builder()
+            .name("app".to_owned())
+            .name("other".to_owned());
+    })
+    .unwrap_err();
+    assert_eq!(
+        panic.downcast_ref::<&str>(),
+        Some(&"ConfigBuilder: `name` was already set"),
+    );
+}
@@ -0,0 +1,31 @@
+// Attributes from other derives, like serde's, stay on the struct they were
+// written for. The builder struct doesn't derive Serialize, so a
+// #[serde(...)] attribute copied onto it would be rejected as unknown.
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Builder, Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Release {
+    package_name: String,
+    #[serde(rename = "ver")]
+    version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    release_notes: Option<String>,
+}
+
+fn main() {
+    let release = Release::builder()
+        .package_name("derive_builder".to_owned())
+        .version("0.1.0".to_owned())
+        .build()
+        .unwrap();
+
+    // The struct's own serde attributes still apply.
+    let json = serde_json::to_string(&release).unwrap();
+    assert_eq!(json, r#"{"packageName":"derive_builder","ver":"0.1.0"}"#);
+    let parsed: Release = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, release);
+    assert!(serde_json::from_str::<Release>(r#"{"packageName":"a","ver":"1","extra":0}"#).is_err());
+}
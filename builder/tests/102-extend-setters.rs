@@ -0,0 +1,48 @@
+// Besides the all-at-once setter, which replaces the whole collection, every
+// collection field gets a `..._extend` setter that adds everything from an
+// iterator to what is already there. It mixes freely with the `each` setter,
+// and keeps the order in which elements were given.
+
+use derive_builder::Builder;
+use std::collections::{BTreeMap, HashSet};
+
+#[derive(Builder)]
+pub struct Command {
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(each = "env")]
+    env: BTreeMap<String, String>,
+    features: HashSet<&'static str>,
+    includes: Option<Vec<String>>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .arg("build".to_owned())
+        .args_extend(["--release", "--locked"].map(str::to_owned))
+        .arg("--verbose".to_owned())
+        .env("A".to_owned(), "1".to_owned())
+        .env_extend([("B".to_owned(), "2".to_owned())])
+        .features_extend(["std", "derive"])
+        .features_extend(["std"])
+        .build()
+        .unwrap();
+
+    assert_eq!(command.args, ["build", "--release", "--locked", "--verbose"]);
+    assert_eq!(command.env.len(), 2);
+    assert_eq!(command.env["B"], "2");
+    assert_eq!(command.features, HashSet::from(["std", "derive"]));
+    assert_eq!(command.includes, None);
+
+    // The all-at-once setter still replaces what came before, and an
+    // extend afterwards adds on top of it.
+    let command = Command::builder()
+        .args_extend(vec!["dropped".to_owned()])
+        .args(vec!["test".to_owned()])
+        .args_extend(std::iter::once("--doc".to_owned()))
+        .includes_extend(Vec::new())
+        .build()
+        .unwrap();
+    assert_eq!(command.args, ["test", "--doc"]);
+    assert_eq!(command.includes, Some(vec![]));
+}
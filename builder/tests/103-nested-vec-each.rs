@@ -0,0 +1,35 @@
+// When the elements of a Vec are Vecs themselves, the `each` setter takes one
+// inner Vec per call, and the all-at-once setter takes the Vec<Vec<T>> as
+// written, without wrapping it in another Vec. A field that was never set
+// builds as an empty outer Vec.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Packets {
+    #[builder(each = "packet")]
+    packets: Vec<Vec<u8>>,
+    #[builder(each = "row")]
+    rows: Vec<Vec<Vec<u8>>>,
+}
+
+fn main() {
+    let packets = Packets::builder()
+        .packet(vec![1, 2])
+        .packet(vec![])
+        .packet(vec![3])
+        .build()
+        .unwrap();
+    assert_eq!(packets.packets, [vec![1, 2], vec![], vec![3]]);
+    assert!(packets.rows.is_empty());
+
+    let all: Vec<Vec<u8>> = vec![vec![4], vec![5, 6]];
+    let packets = Packets::builder()
+        .packets(all)
+        .packets_extend([vec![7]])
+        .row(vec![vec![8]])
+        .build()
+        .unwrap();
+    assert_eq!(packets.packets, [vec![4], vec![5, 6], vec![7]]);
+    assert_eq!(packets.rows, [vec![vec![8]]]);
+}
@@ -0,0 +1,56 @@
+// A field marked #[builder(opt_setter)] gets a `..._opt()` setter next to the
+// regular one. It takes an Option: Some(value) sets the field like the regular
+// setter would, while None leaves the field as it was, whether set or not.
+// This makes it easy to forward values that may or may not have been given,
+// such as optional command line arguments.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+pub struct Command {
+    #[builder(opt_setter, into)]
+    executable: String,
+    #[builder(opt_setter)]
+    jobs: u32,
+    #[builder(opt_setter, default = "false")]
+    verbose: bool,
+    #[builder(opt_setter)]
+    current_dir: Option<String>,
+}
+
+#[derive(Builder, Debug)]
+#[builder(style = "owned")]
+pub struct Job {
+    #[builder(opt_setter)]
+    priority: u8,
+}
+
+fn main() {
+    let jobs_arg: Option<u32> = None;
+    let command = Command::builder()
+        .executable("cargo")
+        .executable_opt(None::<String>)
+        .jobs(4)
+        .jobs_opt(jobs_arg)
+        .verbose_opt(Some(true))
+        .current_dir_opt(Some("..".to_owned()))
+        .build()
+        .unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.jobs, 4);
+    assert!(command.verbose);
+    assert_eq!(command.current_dir.as_deref(), Some(".."));
+
+    let mut builder = Command::builder();
+    builder.executable_opt(Some("rustc")).jobs_opt(Some(1)).current_dir_opt(None);
+    assert!(!builder.is_current_dir_set());
+    let command = builder.build().unwrap();
+    assert_eq!(command.executable, "rustc");
+    assert!(!command.verbose);
+
+    let err = Command::builder().executable("cargo").jobs_opt(None).build().unwrap_err();
+    assert_eq!(err.to_string(), "Command::build: field `jobs` not initialized");
+
+    let job = Job::builder().priority(1).priority_opt(Some(2)).build().unwrap();
+    assert_eq!(job.priority, 2);
+}
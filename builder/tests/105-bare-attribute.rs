@@ -0,0 +1,30 @@
+// A bare #[builder] on a field, without any options, marks the field but
+// changes nothing about it: the field gets the same setter as an unmarked
+// one. It may appear next to other builder attributes too.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+pub struct Command {
+    #[builder]
+    executable: String,
+    #[builder]
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder]
+    current_dir: Option<String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, ["build"]);
+    assert_eq!(command.current_dir, None);
+
+    let err = Command::builder().build().unwrap_err();
+    assert_eq!(err.to_string(), "Command::build: field `executable` not initialized");
+}
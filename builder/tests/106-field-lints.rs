@@ -0,0 +1,50 @@
+// Lint levels set on a field with #[allow(...)], #[warn(...)], #[deny(...)]
+// or #[forbid(...)] carry over to everything generated for that field, since
+// the setters repeat the field's name and type. An #[expect(...)] doesn't, as
+// its copies would go unfulfilled. Here a deprecated type is allowed on the
+// fields that hold it, and no warning may come out of the builder, even with
+// warnings denied.
+
+#![deny(warnings)]
+
+use derive_builder::Builder;
+
+#[deprecated = "use `Protocol` instead"]
+#[derive(Debug, PartialEq)]
+pub struct LegacyProtocol;
+
+#[derive(Builder, Debug)]
+#[builder(clearable)]
+pub struct Connection {
+    host: String,
+    #[forbid(unsafe_code)]
+    port: u16,
+    #[forbid(dead_code)]
+    #[builder(skip)]
+    retries: u8,
+    #[allow(deprecated)]
+    protocol: LegacyProtocol,
+    #[allow(deprecated)]
+    #[builder(each = "fallback")]
+    fallbacks: Vec<LegacyProtocol>,
+    #[allow(deprecated)]
+    #[builder(opt_setter)]
+    preferred: Option<LegacyProtocol>,
+}
+
+#[allow(deprecated)]
+fn main() {
+    let connection = Connection::builder()
+        .host("localhost".to_owned())
+        .port(80)
+        .protocol(LegacyProtocol)
+        .fallback(LegacyProtocol)
+        .preferred_opt(None)
+        .build()
+        .unwrap();
+    assert_eq!(connection.host, "localhost");
+    assert_eq!(connection.retries, 0);
+    assert_eq!(connection.protocol, LegacyProtocol);
+    assert_eq!(connection.fallbacks, [LegacyProtocol]);
+    assert_eq!(connection.preferred, None);
+}
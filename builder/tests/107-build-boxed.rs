@@ -0,0 +1,36 @@
+// With #[builder(boxed)] the builder also gets a `build_boxed()` method, for
+// callers that put the struct on the heap right away. It checks and reports
+// exactly what `build()` does, and returns the struct in a Box.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(boxed)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+}
+
+#[derive(Builder, Debug, Default)]
+#[builder(boxed, default, style = "owned")]
+pub struct Limits {
+    jobs: u32,
+}
+
+fn main() {
+    let command: Box<Command> = Command::builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .build_boxed()
+        .unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, ["build"]);
+
+    let err = Command::builder().build_boxed().unwrap_err();
+    assert_eq!(err.to_string(), "Command::build: field `executable` not initialized");
+
+    // A `build()` that can't fail makes a `build_boxed()` that can't either.
+    let limits: Box<Limits> = Limits::builder().jobs(8).build_boxed();
+    assert_eq!(limits.jobs, 8);
+}
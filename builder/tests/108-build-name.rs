@@ -0,0 +1,39 @@
+// #[builder(build_name = "...")] renames the method that builds the struct,
+// for APIs where `build` already means something else. Everything that would
+// call `build()` calls the renamed method instead, error messages name it,
+// and a field can then have a setter called `build`.
+
+use derive_builder::Builder;
+use std::convert::TryFrom;
+
+#[derive(Builder, Debug)]
+#[builder(build_name = "finish", boxed)]
+pub struct Release {
+    version: String,
+    build: u32,
+}
+
+fn main() {
+    let release = Release::builder()
+        .version("1.0.0".to_owned())
+        .build(42)
+        .finish()
+        .unwrap();
+    assert_eq!(release.version, "1.0.0");
+    assert_eq!(release.build, 42);
+
+    let err = Release::builder().build(1).finish().unwrap_err();
+    assert_eq!(err.to_string(), "Release::finish: field `version` not initialized");
+
+    let boxed = Release::builder()
+        .version("1.0.1".to_owned())
+        .build(43)
+        .build_boxed()
+        .unwrap();
+    assert_eq!(boxed.build, 43);
+
+    let mut builder = Release::builder();
+    builder.version("2.0.0".to_owned()).build(1);
+    let release = Release::try_from(builder).unwrap();
+    assert_eq!(release.version, "2.0.0");
+}
@@ -0,0 +1,44 @@
+// Field types may name associated types of the struct's own type parameters,
+// like `I::Item` or `<T as IntoIterator>::IntoIter`. Setters take them as
+// written, and the builder's impls carry the bounds that make them resolve.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Peeked<I: Iterator>
+where
+    I::Item: Clone,
+{
+    iter: I,
+    first: I::Item,
+    #[builder(each = "extra")]
+    extras: Vec<I::Item>,
+    last: Option<<I as Iterator>::Item>,
+}
+
+#[derive(Builder)]
+pub struct Source<T: IntoIterator> {
+    items: <T as IntoIterator>::IntoIter,
+}
+
+fn main() {
+    let mut iter = vec![1, 2, 3].into_iter();
+    let first = iter.next().unwrap();
+    let peeked = Peeked::builder()
+        .iter(iter)
+        .first(first)
+        .extra(10)
+        .extra(20)
+        .build()
+        .unwrap();
+    assert_eq!(peeked.first, 1);
+    assert_eq!(peeked.extras, [10, 20]);
+    assert_eq!(peeked.last, None);
+    assert_eq!(peeked.iter.collect::<Vec<_>>(), [2, 3]);
+
+    let source = Source::<Vec<char>>::builder()
+        .items(vec!['a', 'b'].into_iter())
+        .build()
+        .unwrap();
+    assert_eq!(source.items.collect::<String>(), "ab");
+}
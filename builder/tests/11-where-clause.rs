@@ -0,0 +1,23 @@
+// Bounds may also be written in a `where` clause instead of inline on the
+// generic parameters. The where clause has to be repeated on the builder
+// struct and on every impl block the macro emits, otherwise the generated code
+// refers to unbounded type parameters.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Pair<A, B>
+where
+    A: Default + Clone,
+    B: Into<String> + Clone,
+{
+    left: A,
+    right: B,
+}
+
+fn main() {
+    let pair = Pair::builder().left(7u32).right("seven").build().unwrap();
+
+    assert_eq!(pair.left, 7);
+    assert_eq!(pair.right, "seven");
+}
@@ -0,0 +1,71 @@
+// Every builder has a `merge()` method that takes the fields another builder
+// has set, for layering configuration from several sources. By default a
+// field that is already set keeps its value, and only unset fields take the
+// other's. With #[builder(merge = "override")] the other's values win
+// instead. Collections combine: the other's elements are added to the ones
+// already there. The exception is an #[builder(extend)] field, which need
+// not iterate over what it is extended with, and so merges like any other
+// field. A typestate builder has no `merge()`, since its type records which
+// fields are set.
+
+use derive_builder::Builder;
+use std::collections::HashMap;
+
+#[derive(Builder, Debug)]
+pub struct Config {
+    name: String,
+    threads: u32,
+    log_file: Option<String>,
+    #[builder(each = "feature")]
+    features: Vec<String>,
+    #[builder(each = "env")]
+    env: HashMap<String, String>,
+    #[builder(extend, each = "push_banner")]
+    banner: String,
+}
+
+#[derive(Builder, Debug)]
+#[builder(merge = "override", style = "owned")]
+pub struct Overrides {
+    name: String,
+    threads: u32,
+    log_file: Option<String>,
+}
+
+fn main() {
+    let mut defaults = Config::builder();
+    defaults
+        .name("default".to_owned())
+        .threads(1)
+        .feature("std".to_owned())
+        .env("MODE".to_owned(), "debug".to_owned())
+        .push_banner("base");
+
+    let mut overrides = Config::builder();
+    overrides
+        .threads(8)
+        .log_file("app.log".to_owned())
+        .feature("serde".to_owned())
+        .env("COLOR".to_owned(), "1".to_owned())
+        .push_banner("override");
+
+    let config = defaults.merge(overrides).build().unwrap();
+    assert_eq!(config.name, "default");
+    assert_eq!(config.threads, 1);
+    assert_eq!(config.log_file.as_deref(), Some("app.log"));
+    assert_eq!(config.features, ["std", "serde"]);
+    assert_eq!(config.env.len(), 2);
+    assert_eq!(config.banner, "base");
+
+    let base = Overrides::builder()
+        .name("default".to_owned())
+        .threads(1)
+        .log_file("base.log".to_owned());
+    let overridden = base
+        .merge(Overrides::builder().threads(8))
+        .build()
+        .unwrap();
+    assert_eq!(overridden.name, "default");
+    assert_eq!(overridden.threads, 8);
+    assert_eq!(overridden.log_file.as_deref(), Some("base.log"));
+}
@@ -0,0 +1,45 @@
+// The motivating case for #[builder(into)]: paths and OS strings. A PathBuf
+// field's setter takes `&str`, `&Path`, `String` or another `PathBuf`, and an
+// OsString field's setter takes `&str`, `&OsStr` or `String`, even for the
+// `each` setter of a collection of them.
+
+use derive_builder::Builder;
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+
+#[derive(Builder)]
+pub struct Command {
+    #[builder(into)]
+    executable: OsString,
+    #[builder(each = "arg", into)]
+    args: Vec<OsString>,
+    #[builder(into)]
+    current_dir: Option<PathBuf>,
+    #[builder(into)]
+    log_dir: PathBuf,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo")
+        .arg("build")
+        .arg(OsStr::new("--release"))
+        .arg(String::from("--locked"))
+        .current_dir("..")
+        .log_dir(Path::new("/tmp/logs"))
+        .build()
+        .unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, ["build", "--release", "--locked"]);
+    assert_eq!(command.current_dir.as_deref(), Some(Path::new("..")));
+    assert_eq!(command.log_dir, PathBuf::from("/tmp/logs"));
+
+    let command = Command::builder()
+        .executable(OsString::from("rustc"))
+        .current_dir(PathBuf::from("/src"))
+        .log_dir(String::from("logs"))
+        .build()
+        .unwrap();
+    assert_eq!(command.current_dir, Some(PathBuf::from("/src")));
+    assert!(command.args.is_empty());
+}
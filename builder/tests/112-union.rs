@@ -0,0 +1,13 @@
+// A union holds only one of its fields at a time, so a builder that collects
+// a value for every field doesn't fit it. Deriving on one reports that at the
+// union's name.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub union Bits {
+    int: u32,
+    float: f32,
+}
+
+fn main() {}
@@ -0,0 +1,51 @@
+// An `each` collection can have a default of its own. If nothing was pushed,
+// build() uses the default instead of an empty collection. Once anything is
+// pushed or set, the builder's elements are used and the default is not
+// evaluated at all.
+
+use derive_builder::Builder;
+use std::collections::HashSet;
+
+fn default_args() -> Vec<String> {
+    vec!["--help".to_owned()]
+}
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg", default = "default_args()")]
+    args: Vec<String>,
+    #[builder(each = "tag", default_fn = "default_tags")]
+    tags: HashSet<&'static str>,
+}
+
+fn default_tags() -> HashSet<&'static str> {
+    HashSet::from(["default"])
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(command.args, ["--help"]);
+    assert_eq!(command.tags, HashSet::from(["default"]));
+
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .arg("--release".to_owned())
+        .tag("ci")
+        .build()
+        .unwrap();
+    assert_eq!(command.args, ["build", "--release"]);
+    assert_eq!(command.tags, HashSet::from(["ci"]));
+
+    // Setting an empty collection counts as setting it.
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .args(Vec::new())
+        .build()
+        .unwrap();
+    assert!(command.args.is_empty());
+}
@@ -0,0 +1,46 @@
+// #[builder(cfg = "...")] puts everything the derive generates behind a
+// condition, such as a cargo feature, while the struct itself stays. The
+// condition is written as it would be inside #[cfg(...)].
+//
+// This test crate has no features, so `feature = "extras"` is off: Basic gets
+// no builder, and its own hand-written `builder()` doesn't conflict with one.
+// The negated condition is on, and Extended gets its builder as usual.
+
+#![allow(unexpected_cfgs)]
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(cfg = "feature = \"extras\"")]
+pub struct Basic {
+    name: String,
+}
+
+#[cfg(not(feature = "extras"))]
+impl Basic {
+    pub fn builder() -> BasicBuilder {
+        BasicBuilder
+    }
+}
+
+#[cfg(not(feature = "extras"))]
+pub struct BasicBuilder;
+
+#[derive(Builder, Debug)]
+#[builder(cfg = "not(feature = \"extras\")", derive(Clone))]
+pub struct Extended {
+    name: String,
+}
+
+fn main() {
+    let _: BasicBuilder = Basic::builder();
+    let basic = Basic {
+        name: "basic".to_owned(),
+    };
+    assert_eq!(basic.name, "basic");
+
+    let extended = Extended::builder().name("extended".to_owned()).build().unwrap();
+    assert_eq!(extended.name, "extended");
+    let err = ExtendedBuilder::default().build().unwrap_err();
+    assert_eq!(err.to_string(), "Extended::build: field `name` not initialized");
+}
@@ -0,0 +1,18 @@
+// A tuple struct without any fields still gets its builder's own fields, like
+// the typestate marker, as positional fields rather than named ones. An empty
+// struct with braces gets named ones, as usual.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(typestate)]
+pub struct Empty();
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(typestate)]
+pub struct Braces {}
+
+fn main() {
+    assert_eq!(Empty::builder().build(), Empty());
+    assert_eq!(Braces::builder().build(), Braces {});
+}
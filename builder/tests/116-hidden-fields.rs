@@ -0,0 +1,52 @@
+// The builder keeps some state of its own next to the struct's fields, like
+// whether build() already took them. That state stays out of sight: a field
+// of the struct may have the same name without clashing with it, and a
+// derived Debug on the builder prints the struct's fields alone, however the
+// derive was requested.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(derive(Debug))]
+pub struct Job<T> {
+    #[allow(non_snake_case)]
+    __consumed: bool,
+    priority: T,
+    name: String,
+}
+
+#[derive(Builder)]
+#[derive(Debug)]
+#[builder(typestate)]
+pub struct Task {
+    #[allow(non_snake_case)]
+    __typestate: u8,
+}
+
+#[derive(Builder)]
+#[derive(Clone, Debug)]
+pub struct Pair(u8, Option<u8>);
+
+fn main() {
+    let mut builder = Job::builder();
+    builder.__consumed(true).priority(1).name("job".to_owned());
+    assert_eq!(
+        format!("{:?}", builder),
+        r#"JobBuilder { __consumed: Some(true), priority: Some(1), name: Some("job") }"#,
+    );
+    let job = builder.build().unwrap();
+    assert!(job.__consumed);
+    assert_eq!(job.priority, 1);
+    assert_eq!(job.name, "job");
+    assert!(builder.build().is_err());
+
+    let builder = Task::builder().__typestate(3);
+    assert_eq!(format!("{:?}", builder), "TaskBuilder { __typestate: Some(3) }");
+    assert_eq!(builder.build().__typestate, 3);
+
+    let mut builder = Pair::builder();
+    builder.field_0(1);
+    assert_eq!(format!("{:?}", builder.clone()), "PairBuilder(Some(1), None)");
+    let pair = builder.build().unwrap();
+    assert_eq!((pair.0, pair.1), (1, None));
+}
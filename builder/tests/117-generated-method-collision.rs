@@ -0,0 +1,48 @@
+// Not just the setters, but every method generated for a field must have a
+// name of its own: the `is_..._set()` check, the `..._extend()`, `..._opt()`
+// and `clear_...()` setters, and the builder's own methods, whatever
+// #[builder(build_name = "...")] calls `build()`. Each clash is reported at
+// the field or the name that causes it.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Extend {
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    args_extend: bool,
+}
+
+#[derive(Builder)]
+#[builder(clearable)]
+pub struct Clear {
+    cache: bool,
+    clear_cache: bool,
+}
+
+#[derive(Builder)]
+pub struct IsSet {
+    verbose: bool,
+    is_verbose_set: bool,
+}
+
+#[derive(Builder)]
+pub struct Opt {
+    #[builder(opt_setter)]
+    jobs: u32,
+    jobs_opt: u32,
+}
+
+#[derive(Builder)]
+#[builder(build_name = "reset")]
+pub struct BuildName {
+    name: String,
+}
+
+#[derive(Builder)]
+#[builder(clearable, build_name = "clear_name")]
+pub struct ClearBuild {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+// A later #[derive(...)] on the struct applies to the builder too. When it
+// derives Default, the builder keeps the derived impl instead of getting a
+// second, conflicting one, just as with #[builder(derive(Default))].
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[derive(Default, Debug)]
+pub struct Limits {
+    jobs: u32,
+    #[builder(each = "path")]
+    paths: Vec<String>,
+}
+
+fn main() {
+    let built = LimitsBuilder::default().jobs(4).build().unwrap();
+    assert_eq!(built.jobs, 4);
+    assert!(built.paths.is_empty());
+
+    let limits = Limits::default();
+    assert_eq!(limits.jobs, 0);
+    assert!(LimitsBuilder::default().build().is_err());
+}
@@ -0,0 +1,14 @@
+// Each required field gets a `Missing...` variant in the generated error
+// enum, named after the field in UpperCamelCase. Two fields can map to the
+// same variant, so the macro has to report that instead of emitting an enum
+// that fails to compile with a confusing duplicate-definition error.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Address {
+    addr_1: u32,
+    addr1: u32,
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+// Lifetime parameters are threaded through the same way as type parameters.
+// The builder keeps borrowed field types intact, so `name: &'a str` is stored
+// as `Option<&'a str>` and `build()` returns `Ref<'a>`.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Ref<'a> {
+    name: &'a str,
+    aliases: Vec<&'a str>,
+}
+
+fn main() {
+    let owned = String::from("cargo");
+    let built: Ref = Ref::builder()
+        .name(&owned)
+        .aliases(vec!["c"])
+        .build()
+        .unwrap();
+
+    assert_eq!(built.name, "cargo");
+    assert_eq!(built.aliases, vec!["c"]);
+}
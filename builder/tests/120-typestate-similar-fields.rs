@@ -0,0 +1,17 @@
+// A typestate builder has one type parameter per required field to track
+// whether it has been set. Fields whose names only differ in underscores,
+// like `addr_1` and `addr1`, must still get parameters of their own.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(typestate)]
+pub struct Address {
+    addr_1: u32,
+    addr1: u32,
+}
+
+fn main() {
+    let address = Address::builder().addr1(2).addr_1(1).build();
+    assert_eq!(address, Address { addr_1: 1, addr1: 2 });
+}
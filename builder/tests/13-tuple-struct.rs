@@ -0,0 +1,20 @@
+// Tuple structs have no field names to derive setter names from, so the
+// builder names them after their position: `field_0`, `field_1`, and so on.
+// The builder itself is a tuple struct storing an `Option` per position, and
+// `build()` initializes the target positionally.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Point(i32, i32, Option<String>);
+
+fn main() {
+    let point = Point::builder().field_0(3).field_1(-4).build().unwrap();
+
+    assert_eq!(point.0, 3);
+    assert_eq!(point.1, -4);
+    assert_eq!(point.2, None);
+
+    let err = Point::builder().field_0(3).build().err().unwrap();
+    assert_eq!(err.to_string(), "Point::build: field `field_1` not initialized");
+}
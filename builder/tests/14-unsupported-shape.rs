@@ -0,0 +1,15 @@
+// Enums and unit structs have no fields for a builder to fill in. Rather than
+// panicking inside the macro, report a spanned error at the offending type.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub enum Shape {
+    Circle,
+    Square,
+}
+
+#[derive(Builder)]
+pub struct Unit;
+
+fn main() {}
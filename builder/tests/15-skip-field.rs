@@ -0,0 +1,22 @@
+// A field marked #[builder(skip)] gets no setter. It is not required to be set
+// before calling build(), which fills it in with Default::default() instead.
+
+use derive_builder::Builder;
+use std::marker::PhantomData;
+
+#[derive(Builder)]
+pub struct Tagged<T> {
+    label: String,
+    #[builder(skip)]
+    marker: PhantomData<T>,
+    #[builder(skip)]
+    generation: u32,
+}
+
+fn main() {
+    let tagged: Tagged<u8> = Tagged::builder().label("x".to_owned()).build().unwrap();
+
+    assert_eq!(tagged.label, "x");
+    assert_eq!(tagged.marker, PhantomData);
+    assert_eq!(tagged.generation, 0);
+}
@@ -0,0 +1,29 @@
+// A field marked #[builder(into)] gets a setter accepting anything convertible
+// into the field type, so callers can pass a &str to a String field without
+// writing .to_owned() themselves. This also applies to the inner type of
+// Option fields and to the all-at-once setter of Vec fields.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    #[builder(into)]
+    executable: String,
+    #[builder(into)]
+    args: Vec<String>,
+    #[builder(into)]
+    current_dir: Option<String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo")
+        .args(["build".to_owned(), "--release".to_owned()])
+        .current_dir("..")
+        .build()
+        .unwrap();
+
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build", "--release"]);
+    assert_eq!(command.current_dir.as_deref(), Some(".."));
+}
@@ -0,0 +1,43 @@
+// A field marked #[builder(default = "...")] is no longer required to be set
+// before calling build(). If the setter was never called, the given
+// expression is evaluated and used instead. The expression is only evaluated
+// when it is actually needed.
+
+use derive_builder::Builder;
+use std::cell::Cell;
+
+thread_local! {
+    static EVALUATED: Cell<u32> = const { Cell::new(0) };
+}
+
+fn counted() -> String {
+    EVALUATED.with(|count| count.set(count.get() + 1));
+    "counted".to_owned()
+}
+
+#[derive(Builder)]
+pub struct Config {
+    #[builder(default = "42")]
+    answer: i32,
+    #[builder(default = "counted()")]
+    name: String,
+    #[builder(default = "vec![1, 2]")]
+    values: Vec<u8>,
+}
+
+fn main() {
+    let config = Config::builder().build().unwrap();
+    assert_eq!(config.answer, 42);
+    assert_eq!(config.name, "counted");
+    assert_eq!(config.values, vec![1, 2]);
+    assert_eq!(EVALUATED.with(Cell::get), 1);
+
+    let config = Config::builder()
+        .answer(7)
+        .name("explicit".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(config.answer, 7);
+    assert_eq!(config.name, "explicit");
+    assert_eq!(EVALUATED.with(Cell::get), 1);
+}
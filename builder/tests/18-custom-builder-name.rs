@@ -0,0 +1,17 @@
+// The name of the generated builder struct can be chosen with a struct-level
+// #[builder(name = "...")] attribute instead of the default `{Name}Builder`.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(name = "CommandConfig")]
+pub struct Command {
+    executable: String,
+}
+
+fn main() {
+    let mut config: CommandConfig = Command::builder();
+    let command = config.executable("cargo".to_owned()).build().unwrap();
+
+    assert_eq!(command.executable, "cargo");
+}
@@ -0,0 +1,12 @@
+// The custom builder name has to be a valid identifier. Anything else is
+// reported at the string literal rather than failing inside the macro.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(name = "Command-Builder")]
+pub struct Command {
+    executable: String,
+}
+
+fn main() {}
@@ -0,0 +1,35 @@
+// By default build() reports a missing required field through a generated
+// error enum, `{Builder}Error`, with one variant per required field. It
+// implements std::error::Error and Display so it can still be used with `?`
+// and boxed errors.
+//
+// The previous Box<dyn Error> return type is available through a struct-level
+// #[builder(error = "boxed")] attribute.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    current_dir: String,
+    env: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(error = "boxed")]
+pub struct Boxed {
+    executable: String,
+}
+
+fn main() {
+    let err = Command::builder()
+        .executable("cargo".to_owned())
+        .build()
+        .err()
+        .unwrap();
+    assert!(matches!(err, CommandBuilderError::MissingCurrentDir));
+    assert_eq!(err.to_string(), "Command::build: field `current_dir` not initialized");
+
+    let boxed: Box<dyn std::error::Error> = Boxed::builder().build().err().unwrap();
+    assert_eq!(boxed.to_string(), "Boxed::build: field `executable` not initialized");
+}
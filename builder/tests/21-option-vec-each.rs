@@ -0,0 +1,32 @@
+// An Option<Vec<T>> field keeps its one-at-a-time `each` setter. The field is
+// left as None by build() unless items were pushed or the all-at-once setter,
+// which takes a plain Vec<T>, was called.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Option<Vec<String>>,
+    env: Option<Vec<String>>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .arg("--release".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(command.args, Some(vec!["build".to_owned(), "--release".to_owned()]));
+    assert_eq!(command.env, None);
+
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .env(vec!["RUST_LOG=debug".to_owned()])
+        .build()
+        .unwrap();
+    assert_eq!(command.args, None);
+    assert_eq!(command.env, Some(vec!["RUST_LOG=debug".to_owned()]));
+}
@@ -0,0 +1,44 @@
+// Option and Vec are recognized whether they are written bare or through
+// their fully qualified paths, such as std::option::Option or
+// core::option::Option. A type that merely ends in `Option` but lives in some
+// other module is not mistaken for the standard one.
+
+use derive_builder::Builder;
+
+mod custom {
+    pub type Option<T> = std::vec::Vec<T>;
+}
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    current_dir: std::option::Option<String>,
+    pid: core::option::Option<u32>,
+    nice: Option<i8>,
+    #[builder(each = "arg")]
+    args: std::vec::Vec<String>,
+    #[builder(each = "env")]
+    env: Vec<String>,
+    paths: custom::Option<String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .env("RUST_LOG=debug".to_owned())
+        .paths(vec![])
+        .build()
+        .unwrap();
+
+    assert_eq!(command.current_dir, None);
+    assert_eq!(command.pid, None);
+    assert_eq!(command.nice, None);
+    assert_eq!(command.args, vec!["build"]);
+    assert_eq!(command.env, vec!["RUST_LOG=debug"]);
+    assert!(command.paths.is_empty());
+
+    // `custom::Option` is a required field, not an optional one.
+    let err = Command::builder().executable("cargo".to_owned()).build();
+    assert!(matches!(err, Err(CommandBuilderError::MissingPaths)));
+}
@@ -0,0 +1,24 @@
+// Fields may carry doc comments and other attributes next to their
+// #[builder(...)] attribute. Only attributes in the `builder` namespace are
+// interpreted by the macro; everything else is ignored.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    /// The program to run.
+    executable: String,
+    /// Arguments passed to the program, one at a time.
+    #[builder(each = "arg")]
+    args: Vec<String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(command.args, vec!["build"]);
+}
@@ -0,0 +1,25 @@
+// Several builder options may be given for one field, either inside a single
+// #[builder(...)] attribute separated by commas or spread over more than one
+// attribute. Giving the same option twice is an error.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    #[builder(into)]
+    #[builder(default = "\"cargo\".to_owned()")]
+    executable: String,
+    #[builder(each = "arg", into)]
+    args: Vec<String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .args(["build".to_owned()])
+        .arg("--release".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build", "--release"]);
+}
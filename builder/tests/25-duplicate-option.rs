@@ -0,0 +1,13 @@
+// The same option given twice for one field is reported at the second
+// occurrence, whether or not both are in the same attribute.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    #[builder(each = "arg")]
+    #[builder(each = "argument")]
+    args: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+// Doc comments on a field are forwarded to the setters generated for it, so
+// that they show up in the builder's documentation. Other attributes on the
+// field, including the builder's own, are not forwarded.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    /// The program to run.
+    ///
+    /// Looked up in `PATH` if it is not an absolute path.
+    executable: String,
+    #[doc = "Arguments passed to the program."]
+    #[builder(each = "arg")]
+    args: Vec<String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build"]);
+}
@@ -0,0 +1,28 @@
+// Traits listed in a struct-level #[builder(derive(...))] attribute are
+// derived on the generated builder struct. Deriving Clone makes it possible to
+// configure the common parts once and then build several variants from it.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(derive(Clone))]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+}
+
+fn main() {
+    let mut base = Command::builder();
+    base.executable("cargo".to_owned());
+
+    let mut build = base.clone();
+    let mut test = base.clone();
+    let build = build.arg("build".to_owned()).build().unwrap();
+    let test = test.arg("test".to_owned()).build().unwrap();
+
+    assert_eq!(build.executable, "cargo");
+    assert_eq!(build.args, vec!["build"]);
+    assert_eq!(test.executable, "cargo");
+    assert_eq!(test.args, vec!["test"]);
+}
@@ -0,0 +1,27 @@
+// With a struct-level #[builder(build_by = "value")] attribute, build() takes
+// the builder by value and moves the fields out of it, rather than taking
+// &mut self and leaving an emptied builder behind.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(build_by = "value")]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+    #[builder(default = "1")]
+    jobs: u32,
+}
+
+fn main() {
+    let mut builder = Command::builder();
+    builder.executable("cargo".to_owned()).arg("build".to_owned());
+    let command = builder.build().unwrap();
+
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build"]);
+    assert_eq!(command.current_dir, None);
+    assert_eq!(command.jobs, 1);
+}
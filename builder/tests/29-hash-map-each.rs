@@ -0,0 +1,41 @@
+// A HashMap field with a #[builder(each = "...")] attribute gets a setter that
+// takes a key and a value and inserts them into the map, one entry at a time.
+// The all-at-once setter takes a whole map, and build() falls back to an empty
+// map if nothing was inserted.
+
+use derive_builder::Builder;
+use std::collections::HashMap;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "env")]
+    env: HashMap<String, String>,
+    #[builder(each = "limit")]
+    limits: std::collections::HashMap<&'static str, u64>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .env("RUST_LOG".to_owned(), "debug".to_owned())
+        .env("RUST_BACKTRACE".to_owned(), "1".to_owned())
+        .env("RUST_LOG".to_owned(), "trace".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(command.env.len(), 2);
+    assert_eq!(command.env["RUST_LOG"], "trace");
+    assert_eq!(command.env["RUST_BACKTRACE"], "1");
+    assert!(command.limits.is_empty());
+
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .limits(HashMap::from([("jobs", 4)]))
+        .limit("files", 1024)
+        .build()
+        .unwrap();
+
+    assert!(command.env.is_empty());
+    assert_eq!(command.limits, HashMap::from([("jobs", 4), ("files", 1024)]));
+}
@@ -0,0 +1,38 @@
+// A HashSet field with a #[builder(each = "...")] attribute gets a setter that
+// inserts one element at a time, so adding the same element twice keeps only
+// one copy. The all-at-once setter takes a whole set.
+
+use derive_builder::Builder;
+use std::collections::HashSet;
+
+#[derive(Builder)]
+pub struct Post {
+    title: String,
+    #[builder(each = "tag")]
+    tags: HashSet<String>,
+    authors: std::collections::HashSet<u32>,
+}
+
+fn main() {
+    let post = Post::builder()
+        .title("Builders".to_owned())
+        .tag("rust".to_owned())
+        .tag("macros".to_owned())
+        .tag("rust".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(post.tags.len(), 2);
+    assert!(post.tags.contains("rust"));
+    assert!(post.tags.contains("macros"));
+    assert!(post.authors.is_empty());
+
+    let post = Post::builder()
+        .title("Builders".to_owned())
+        .authors(HashSet::from([1, 2]))
+        .build()
+        .unwrap();
+
+    assert!(post.tags.is_empty());
+    assert_eq!(post.authors, HashSet::from([2, 1]));
+}
@@ -0,0 +1,27 @@
+// When the `each` name differs from the field name, both the one-at-a-time
+// setter and the all-at-once setter are generated, and they can be mixed. The
+// all-at-once setter replaces whatever was pushed before it.
+//
+// When the names are the same (see 07-repeated-field.rs) only the `each`
+// setter exists, because two methods can't share a name.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .arg("ignored".to_owned())
+        .args(vec!["build".to_owned()])
+        .arg("--release".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(command.args, vec!["build", "--release"]);
+}
@@ -0,0 +1,13 @@
+// #[builder(each = "...")] only makes sense on a collection field. Rather than
+// silently ignoring it elsewhere, the macro reports an error at the `each`
+// name.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    #[builder(each = "char")]
+    executable: String,
+}
+
+fn main() {}
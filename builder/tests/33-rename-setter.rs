@@ -0,0 +1,24 @@
+// A field marked #[builder(rename = "...")] gets a setter with the given name
+// instead of the field's own name. build() still fills in the original field.
+// The new name has to be a valid identifier.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Server {
+    #[builder(rename = "listen_on")]
+    port: u16,
+    #[builder(rename = "hostname", into)]
+    host: String,
+}
+
+fn main() {
+    let server = Server::builder()
+        .listen_on(8080)
+        .hostname("localhost")
+        .build()
+        .unwrap();
+
+    assert_eq!(server.port, 8080);
+    assert_eq!(server.host, "localhost");
+}
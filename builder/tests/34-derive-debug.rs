@@ -0,0 +1,24 @@
+// The same struct-level #[builder(derive(...))] attribute can derive Debug on
+// the builder, which is handy for inspecting a partially configured builder.
+// Every field is printed the way it is stored, wrapped in an Option.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(derive(Debug, Clone))]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+}
+
+fn main() {
+    let mut builder = Command::builder();
+    builder.executable("cargo".to_owned()).arg("build".to_owned());
+
+    assert_eq!(
+        format!("{:?}", builder),
+        r#"CommandBuilder { executable: Some("cargo"), args: Some(["build"]), current_dir: None }"#,
+    );
+}
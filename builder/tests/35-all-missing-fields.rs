@@ -0,0 +1,42 @@
+// build() checks every required field before giving up, and reports all of the
+// missing ones in a single error instead of only the first. With the generated
+// error enum, more than one missing field is reported through its `Multiple`
+// variant.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    current_dir: String,
+    jobs: u32,
+    env: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(error = "boxed")]
+pub struct Boxed {
+    executable: String,
+    current_dir: String,
+    jobs: u32,
+}
+
+fn main() {
+    let err = Command::builder().jobs(4).build().err().unwrap();
+    assert_eq!(err.to_string(), "Command::build: fields `executable`, `current_dir` not initialized");
+    match err {
+        CommandBuilderError::Multiple(errors) => {
+            assert!(matches!(
+                errors[..],
+                [
+                    CommandBuilderError::MissingExecutable,
+                    CommandBuilderError::MissingCurrentDir,
+                ]
+            ));
+        }
+        _ => panic!("expected more than one missing field"),
+    }
+
+    let err = Boxed::builder().jobs(4).build().err().unwrap();
+    assert_eq!(err.to_string(), "Boxed::build: fields `executable`, `current_dir` not initialized");
+}
@@ -0,0 +1,41 @@
+// A field whose type derives Builder itself can be marked #[builder(nested)].
+// Its setter takes a closure that configures the inner builder in place; the
+// setter then builds the inner value and stores it. Any error from the inner
+// build() is returned from the setter.
+//
+// The inner builder is assumed to have the default `{Type}Builder` name.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug, PartialEq)]
+pub struct Limits {
+    jobs: u32,
+    memory: Option<u64>,
+}
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(nested)]
+    limits: Limits,
+    #[builder(nested)]
+    fallback: Option<Limits>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .limits(|limits| limits.jobs(4).memory(1 << 30))
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.limits.jobs, 4);
+    assert_eq!(command.limits.memory, Some(1 << 30));
+    assert_eq!(command.fallback, None);
+
+    let mut builder = Command::builder();
+    let err = builder.fallback(|limits| limits.memory(0));
+    assert_eq!(err.err().unwrap().to_string(), "Limits::build: field `jobs` not initialized");
+}
@@ -0,0 +1,42 @@
+// A struct-level #[builder(vis = "...")] attribute sets the visibility of
+// builder(), the setters, and build(), instead of the default of a builder()
+// as visible as the struct and setters as visible as their fields.
+//
+// A trybuild test runs as a single crate, so a module boundary stands in for
+// the crate boundary here: with `pub(super)` the builder is usable from the
+// parent module and nowhere else, exactly as a `pub(crate)` builder is usable
+// inside its crate and not outside it.
+
+use derive_builder::Builder;
+
+mod outer {
+    pub mod inner {
+        use derive_builder::Builder;
+
+        #[derive(Builder)]
+        #[builder(vis = "pub(super)")]
+        pub struct Command {
+            pub executable: String,
+        }
+    }
+
+    pub fn cargo() -> inner::Command {
+        inner::Command::builder()
+            .executable("cargo".to_owned())
+            .build()
+            .unwrap()
+    }
+}
+
+#[derive(Builder)]
+#[builder(vis = "pub(crate)")]
+pub struct Crate {
+    name: String,
+}
+
+fn main() {
+    assert_eq!(outer::cargo().executable, "cargo");
+    assert_eq!(Crate::builder().name("x".to_owned()).build().unwrap().name, "x");
+
+    let _ = outer::inner::Command::builder();
+}
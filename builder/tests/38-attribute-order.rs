@@ -0,0 +1,31 @@
+// The position of #[builder(...)] among a field's other attributes doesn't
+// matter. Every attribute is looked at and only the ones in the `builder`
+// namespace are interpreted, so other attributes may come first.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    #[allow(dead_code)]
+    executable: String,
+    #[allow(dead_code)]
+    /// Arguments, added one at a time.
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[cfg_attr(any(), allow(unused))]
+    #[builder(each = "env")]
+    #[allow(dead_code)]
+    env: Vec<String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .env("RUST_LOG=debug".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(command.args, vec!["build"]);
+    assert_eq!(command.env, vec!["RUST_LOG=debug"]);
+}
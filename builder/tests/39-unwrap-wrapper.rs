@@ -0,0 +1,49 @@
+// A `Box<T>`, `Rc<T>` or `Arc<T>` field is normally just another required
+// field, and its setter takes the whole `Box<T>`. With #[builder(unwrap)] the
+// setter takes the inner `T` instead, and build() wraps it with `Box::new`,
+// `Rc::new` or `Arc::new`.
+//
+// A default for an unwrapped field is written as the field's full type.
+
+use derive_builder::Builder;
+use std::sync::Arc;
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    verbose: bool,
+}
+
+#[derive(Builder, Debug)]
+pub struct Command {
+    executable: String,
+    #[builder(unwrap)]
+    config: Box<Config>,
+    #[builder(unwrap, into)]
+    name: Arc<String>,
+    #[builder(unwrap, default = "std::rc::Rc::new(1)")]
+    jobs: std::rc::Rc<u32>,
+    raw: Box<str>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .config(Config { verbose: true })
+        .name("build")
+        .raw("raw".into())
+        .build()
+        .unwrap();
+
+    assert_eq!(*command.config, Config { verbose: true });
+    assert_eq!(*command.name, "build");
+    assert_eq!(*command.jobs, 1);
+    assert_eq!(&*command.raw, "raw");
+
+    let err = Command::builder()
+        .executable("cargo".to_owned())
+        .name("build")
+        .raw("raw".into())
+        .build()
+        .unwrap_err();
+    assert_eq!(err.to_string(), "Command::build: field `config` not initialized");
+}
@@ -0,0 +1,36 @@
+// The builder has a reset() method that clears every field back to unset,
+// the same state builder() starts from. A long-lived builder can be reused
+// for an unrelated value without carrying anything over from before.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+}
+
+fn main() {
+    let mut builder = Command::builder();
+    builder
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .current_dir("..".to_owned());
+    let command = builder.build().unwrap();
+    assert_eq!(command.executable, "cargo");
+
+    builder
+        .executable("rustc".to_owned())
+        .arg("--version".to_owned())
+        .current_dir("/".to_owned());
+    builder.reset().executable("ls".to_owned());
+    let command = builder.build().unwrap();
+    assert_eq!(command.executable, "ls");
+    assert!(command.args.is_empty());
+    assert!(command.current_dir.is_none());
+
+    let err = builder.reset().build().unwrap_err();
+    assert_eq!(err.to_string(), "Command::build: field `executable` not initialized");
+}
@@ -0,0 +1,24 @@
+// Const generic parameters are carried over to the builder struct and to
+// every generated impl, the same as type and lifetime parameters.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Buffer<const N: usize> {
+    data: [u8; N],
+    label: Option<String>,
+}
+
+fn main() {
+    let mut builder: BufferBuilder<4> = Buffer::builder();
+    let buffer = builder.data([1, 2, 3, 4]).build().unwrap();
+    assert_eq!(buffer.data, [1, 2, 3, 4]);
+    assert_eq!(buffer.label, None);
+
+    let buffer = Buffer::<2>::builder()
+        .data([5, 6])
+        .label("pair".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(buffer.data.len(), 2);
+}
@@ -0,0 +1,36 @@
+// Combining `each` with `into` makes the one-at-a-time setter accept
+// `impl Into<T>` as well, so a `Vec<String>` can be filled from string
+// literals. For a map, both the key and the value are converted.
+
+use derive_builder::Builder;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Builder)]
+pub struct Command {
+    #[builder(each = "arg", into)]
+    args: Vec<String>,
+    #[builder(each = "env", into)]
+    env: HashMap<String, String>,
+    #[builder(each = "feature", into)]
+    features: HashSet<String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .arg("build")
+        .arg(String::from("--release"))
+        .env("RUST_LOG", "debug")
+        .feature("std")
+        .build()
+        .unwrap();
+
+    assert_eq!(command.args, vec!["build", "--release"]);
+    assert_eq!(command.env["RUST_LOG"], "debug");
+    assert!(command.features.contains("std"));
+
+    let command = Command::builder()
+        .args(vec!["test".to_owned()])
+        .build()
+        .unwrap();
+    assert_eq!(command.args, vec!["test"]);
+}
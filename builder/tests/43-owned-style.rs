@@ -0,0 +1,40 @@
+// With #[builder(style = "owned")] on the struct, every setter takes the
+// builder by value and returns it, and build() consumes it. That allows a
+// single expression chain without a `let mut builder` binding, and a builder
+// can be stored in a struct or passed around by value between calls.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(style = "owned")]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+}
+
+fn with_defaults(builder: CommandBuilder) -> CommandBuilder {
+    builder.arg("--locked".to_owned())
+}
+
+fn main() {
+    let builder = Command::builder().executable("cargo".to_owned());
+    let command = with_defaults(builder)
+        .arg("build".to_owned())
+        .current_dir("..".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["--locked", "build"]);
+    assert_eq!(command.current_dir.as_deref(), Some(".."));
+
+    let command = Command::builder()
+        .executable("rustc".to_owned())
+        .reset()
+        .executable("ls".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(command.executable, "ls");
+}
@@ -0,0 +1,34 @@
+// When a required field with a renamed setter is missing, the error names the
+// setter rather than the field, since that is the name the caller knows. The
+// error variant is still derived from the field name.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Server {
+    #[builder(rename = "listen_on")]
+    port: u16,
+    host: String,
+}
+
+#[derive(Builder)]
+#[builder(error = "boxed")]
+pub struct Client {
+    #[builder(rename = "connect_to")]
+    address: String,
+}
+
+fn main() {
+    let err = Server::builder()
+        .host("localhost".to_owned())
+        .build()
+        .err().unwrap();
+    assert!(matches!(err, ServerBuilderError::MissingPort));
+    assert_eq!(err.to_string(), "Server::build: field `listen_on` not initialized");
+
+    let err = Server::builder().build().err().unwrap();
+    assert_eq!(err.to_string(), "Server::build: fields `listen_on`, `host` not initialized");
+
+    let err = Client::builder().build().err().unwrap();
+    assert_eq!(err.to_string(), "Client::build: field `connect_to` not initialized");
+}
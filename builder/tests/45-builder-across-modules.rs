@@ -0,0 +1,35 @@
+// builder() has the same visibility as the struct it is derived on, so a
+// `pub struct` declared in a submodule can be built from anywhere the struct
+// itself can be named.
+
+mod config {
+    use derive_builder::Builder;
+
+    #[derive(Builder)]
+    pub struct Command {
+        pub executable: String,
+        pub current_dir: Option<String>,
+    }
+}
+
+mod jobs {
+    pub fn cargo() -> crate::config::Command {
+        crate::config::Command::builder()
+            .executable("cargo".to_owned())
+            .build()
+            .unwrap()
+    }
+}
+
+fn main() {
+    let command = jobs::cargo();
+    assert_eq!(command.executable, "cargo");
+    assert!(command.current_dir.is_none());
+
+    let command = config::Command::builder()
+        .executable("rustc".to_owned())
+        .current_dir("..".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(command.current_dir.as_deref(), Some(".."));
+}
@@ -0,0 +1,39 @@
+// For a `pub struct`, builder() is generated as `pub fn builder()`, so the
+// builder is part of the public API of whatever crate defines the struct.
+// As in test 37, modules stand in for crates: `upstream` plays the defining
+// crate and only exposes `Command` and its builder through `pub use`, and
+// `downstream` reaches them through that public boundary alone.
+
+pub mod upstream {
+    mod command {
+        use derive_builder::Builder;
+
+        #[derive(Builder)]
+        pub struct Command {
+            pub executable: String,
+            #[builder(each = "arg")]
+            pub args: Vec<String>,
+        }
+    }
+
+    pub use self::command::{Command, CommandBuilder};
+}
+
+mod downstream {
+    use crate::upstream::{Command, CommandBuilder};
+
+    pub fn cargo_build() -> Command {
+        let mut builder: CommandBuilder = Command::builder();
+        builder
+            .executable("cargo".to_owned())
+            .arg("build".to_owned())
+            .build()
+            .unwrap()
+    }
+}
+
+fn main() {
+    let command = downstream::cargo_build();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build"]);
+}
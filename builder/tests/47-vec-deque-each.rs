@@ -0,0 +1,36 @@
+// A `VecDeque` field supports `each` the same way a `Vec` does. The
+// one-at-a-time setter pushes to the back of the queue, the all-at-once setter
+// takes a whole `VecDeque`, and an unset queue builds as empty.
+
+use derive_builder::Builder;
+use std::collections::VecDeque;
+
+#[derive(Builder)]
+pub struct Scheduler {
+    #[builder(each = "job")]
+    jobs: VecDeque<String>,
+    #[builder(each = "retry")]
+    retries: std::collections::VecDeque<u32>,
+}
+
+fn main() {
+    let scheduler = Scheduler::builder()
+        .job("fetch".to_owned())
+        .job("build".to_owned())
+        .job("test".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(scheduler.jobs, ["fetch", "build", "test"]);
+    assert!(scheduler.retries.is_empty());
+
+    let scheduler = Scheduler::builder()
+        .jobs(VecDeque::from(vec!["lint".to_owned()]))
+        .job("deploy".to_owned())
+        .retry(3)
+        .build()
+        .unwrap();
+    assert_eq!(scheduler.jobs.front().map(String::as_str), Some("lint"));
+    assert_eq!(scheduler.jobs.back().map(String::as_str), Some("deploy"));
+    assert_eq!(scheduler.retries, [3]);
+}
@@ -0,0 +1,14 @@
+// The name given to `each` becomes a method name, so it has to be a valid
+// identifier. Anything else is reported at the string literal instead of
+// making the macro panic.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg-name")]
+    args: Vec<String>,
+}
+
+fn main() {}
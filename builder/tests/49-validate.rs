@@ -0,0 +1,62 @@
+// A struct-level #[builder(validate = "path::to::function")] names a function
+// that build() calls with a reference to the assembled struct, after every
+// field has been filled in. The function returns `Result<(), E>` for any
+// error type that converts into `Box<dyn Error>`, and a rejection is returned
+// from build(): as a `Validation` variant of the generated error enum, or
+// boxed as is with #[builder(error = "boxed")].
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(validate = "checks::valid_port")]
+pub struct Server {
+    host: String,
+    port: u32,
+}
+
+#[derive(Builder, Debug)]
+#[builder(validate = "checks::valid_jobs", error = "boxed")]
+pub struct Command {
+    jobs: u32,
+}
+
+mod checks {
+    pub fn valid_port(server: &super::Server) -> Result<(), String> {
+        if server.port > 65535 {
+            return Err(format!("port {} is out of range", server.port));
+        }
+        Ok(())
+    }
+
+    pub fn valid_jobs(command: &super::Command) -> Result<(), &'static str> {
+        if command.jobs == 0 {
+            return Err("at least one job is required");
+        }
+        Ok(())
+    }
+}
+
+fn main() {
+    let server = Server::builder()
+        .host("localhost".to_owned())
+        .port(8080)
+        .build()
+        .unwrap();
+    assert_eq!(server.port, 8080);
+
+    let err = Server::builder()
+        .host("localhost".to_owned())
+        .port(70000)
+        .build()
+        .unwrap_err();
+    assert!(matches!(err, ServerBuilderError::Validation(_)));
+    assert_eq!(err.to_string(), "port 70000 is out of range");
+
+    // Missing fields are reported before validation runs.
+    let err = Server::builder().port(70000).build().unwrap_err();
+    assert_eq!(err.to_string(), "Server::build: field `host` not initialized");
+
+    assert_eq!(Command::builder().jobs(4).build().unwrap().jobs, 4);
+    let err = Command::builder().jobs(0).build().unwrap_err();
+    assert_eq!(err.to_string(), "at least one job is required");
+}
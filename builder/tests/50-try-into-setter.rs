@@ -0,0 +1,35 @@
+// A field marked #[builder(try_into)] gets a fallible setter: it accepts any
+// value with a `TryInto` conversion to the field type and returns a `Result`
+// whose error is the conversion's own `TryInto::Error`. On failure the field
+// keeps whatever value it had before.
+
+use derive_builder::Builder;
+use std::num::TryFromIntError;
+
+#[derive(Builder)]
+pub struct Pixel {
+    #[builder(try_into)]
+    red: u8,
+    #[builder(try_into)]
+    green: u8,
+    blue: u8,
+}
+
+fn main() -> Result<(), TryFromIntError> {
+    let pixel = Pixel::builder()
+        .red(255_i32)?
+        .green(128_u64)?
+        .blue(0)
+        .build()
+        .unwrap();
+    assert_eq!((pixel.red, pixel.green, pixel.blue), (255, 128, 0));
+
+    let mut builder = Pixel::builder();
+    builder.red(7_i32)?;
+    let err: TryFromIntError = builder.red(300_i32).err().unwrap();
+    assert_eq!(err.to_string(), "out of range integral type conversion attempted");
+
+    let pixel = builder.green(1_u16)?.blue(2).build().unwrap();
+    assert_eq!(pixel.red, 7);
+    Ok(())
+}
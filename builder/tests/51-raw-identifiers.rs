@@ -0,0 +1,26 @@
+// A field named with a raw identifier like `r#type` gets a setter called
+// `r#type`. Error messages and error variants use the name without the `r#`
+// prefix.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+pub struct Token {
+    r#type: String,
+    #[builder(each = "r#match")]
+    r#matches: Vec<String>,
+}
+
+fn main() {
+    let token = Token::builder()
+        .r#type("ident".to_owned())
+        .r#match("foo".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(token.r#type, "ident");
+    assert_eq!(token.r#matches, vec!["foo"]);
+
+    let err = Token::builder().build().unwrap_err();
+    assert!(matches!(err, TokenBuilderError::MissingType));
+    assert_eq!(err.to_string(), "Token::build: field `type` not initialized");
+}
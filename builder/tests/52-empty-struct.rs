@@ -0,0 +1,16 @@
+// A struct with no fields gets a builder with no setters, and build() always
+// succeeds, for braced and tuple structs alike.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug, PartialEq)]
+pub struct Empty {}
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(error = "boxed")]
+pub struct EmptyTuple();
+
+fn main() {
+    assert_eq!(Empty::builder().build().unwrap(), Empty {});
+    assert_eq!(EmptyTuple::builder().reset().build().unwrap(), EmptyTuple());
+}
@@ -0,0 +1,21 @@
+// With #[builder(must_use)] on the struct, builder() and every setter that
+// returns the builder are marked #[must_use], so a builder that is created or
+// configured and then dropped without calling build() gets a warning. It is
+// opt-in because the usual `builder.field(...);` statement style discards the
+// returned reference on purpose.
+
+#![deny(unused_must_use)]
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(must_use, style = "owned")]
+pub struct Command {
+    executable: String,
+}
+
+fn main() {
+    Command::builder();
+
+    Command::builder().executable("cargo".to_owned());
+}
@@ -0,0 +1,33 @@
+// A `BTreeMap` field supports `each` just like a `HashMap`: the one-at-a-time
+// setter takes a key and a value, the all-at-once setter takes a whole
+// `BTreeMap`, and an unset map builds as empty. The map keeps its keys in
+// order regardless of the order they were inserted in.
+
+use derive_builder::Builder;
+use std::collections::BTreeMap;
+
+#[derive(Builder)]
+pub struct Config {
+    #[builder(each = "section")]
+    sections: BTreeMap<String, u32>,
+    #[builder(each = "flag")]
+    flags: std::collections::BTreeMap<u8, bool>,
+}
+
+fn main() {
+    let config = Config::builder()
+        .section("zeta".to_owned(), 3)
+        .section("alpha".to_owned(), 1)
+        .section("mu".to_owned(), 2)
+        .build()
+        .unwrap();
+
+    let keys: Vec<&str> = config.sections.keys().map(String::as_str).collect();
+    assert_eq!(keys, ["alpha", "mu", "zeta"]);
+    assert!(config.flags.is_empty());
+
+    let mut flags = BTreeMap::new();
+    flags.insert(2, true);
+    let config = Config::builder().flags(flags).flag(1, false).build().unwrap();
+    assert_eq!(config.flags.into_iter().collect::<Vec<_>>(), [(1, false), (2, true)]);
+}
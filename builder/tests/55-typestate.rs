@@ -0,0 +1,49 @@
+// With #[builder(typestate)] on the struct, the builder's type records which
+// required fields have been set. Each required field adds a type parameter to
+// the builder that starts out as `CommandBuilderUnset` and becomes
+// `CommandBuilderSet` once its setter is called. build() is only defined when
+// every required field is set, so it returns the struct directly instead of a
+// `Result`.
+//
+// Optional fields, collections and fields with a default don't affect the
+// builder's type. Setters take and return the builder by value, since the
+// returned builder may have a different type.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Command<T> {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+    #[builder(default = "1")]
+    jobs: u32,
+    payload: T,
+}
+
+fn main() {
+    let builder: CommandBuilder<u8, CommandBuilderUnset, CommandBuilderUnset> = Command::builder();
+    let builder: CommandBuilder<u8, CommandBuilderSet, CommandBuilderUnset> =
+        builder.arg("build".to_owned()).executable("cargo".to_owned());
+    let command: Command<u8> = builder.payload(7).build();
+
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build"]);
+    assert_eq!(command.current_dir, None);
+    assert_eq!(command.jobs, 1);
+    assert_eq!(command.payload, 7);
+
+    // reset() goes back to the unset state, whatever state it started in.
+    let command = Command::builder()
+        .payload("first")
+        .executable("rustc".to_owned())
+        .reset()
+        .payload("second")
+        .executable("ls".to_owned())
+        .jobs(4)
+        .build();
+    assert_eq!(command.payload, "second");
+    assert_eq!(command.jobs, 4);
+}
@@ -0,0 +1,16 @@
+// Forgetting a required field of a typestate builder is a compile error
+// rather than an `Err` from build(): build() simply doesn't exist until the
+// builder's type says every required field is set.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Command {
+    executable: String,
+    current_dir: Option<String>,
+}
+
+fn main() {
+    let _command = Command::builder().current_dir("..".to_owned()).build();
+}
@@ -0,0 +1,45 @@
+// A `cfg` attribute on a field also applies to the builder's copy of the
+// field, its setters, and its part of build(), so the builder has exactly the
+// fields the struct ends up with. Here `cfg(any())` is never enabled and
+// `cfg(all())` always is; they stand in for a feature flag that is turned off
+// or on.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+pub struct Command {
+    executable: String,
+    #[cfg(any())]
+    tracing: String,
+    #[cfg(all())]
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[cfg(all())]
+    jobs: u32,
+}
+
+// A feature that is off must not leave a setter behind.
+trait NoTracing {
+    fn tracing(&mut self, (): ()) -> bool {
+        false
+    }
+}
+
+impl NoTracing for CommandBuilder {}
+
+fn main() {
+    let mut builder = Command::builder();
+    assert!(!builder.tracing(()));
+
+    let command = builder
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .jobs(2)
+        .build()
+        .unwrap();
+    assert_eq!(command.args, vec!["build"]);
+    assert_eq!(command.jobs, 2);
+
+    let err = Command::builder().build().unwrap_err();
+    assert_eq!(err.to_string(), "Command::build: fields `executable`, `jobs` not initialized");
+}
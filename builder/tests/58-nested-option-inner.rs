@@ -0,0 +1,30 @@
+// The inner type of an optional field can be any type, however deeply its own
+// generic arguments nest, and the `Option` can be spelled with its full path.
+// The setter always takes the whole inner type.
+
+use derive_builder::Builder;
+use std::collections::HashMap;
+
+#[derive(Builder)]
+pub struct Cache {
+    entries: Option<HashMap<String, Vec<u8>>>,
+    index: std::option::Option<HashMap<u32, Option<Vec<String>>>>,
+    limits: core::option::Option<(u32, Box<[u8]>)>,
+}
+
+fn main() {
+    let mut entries = HashMap::new();
+    entries.insert("key".to_owned(), vec![1, 2, 3]);
+    let mut index = HashMap::new();
+    index.insert(7, Some(vec!["seven".to_owned()]));
+
+    let cache = Cache::builder()
+        .entries(entries)
+        .index(index)
+        .build()
+        .unwrap();
+
+    assert_eq!(cache.entries.unwrap()["key"], [1, 2, 3]);
+    assert_eq!(cache.index.unwrap()[&7].as_deref(), Some(&["seven".to_owned()][..]));
+    assert!(cache.limits.is_none());
+}
@@ -0,0 +1,37 @@
+// Every field that has a setter also gets an `is_<name>_set` method telling
+// whether it currently holds a value, named after the setter. For a `Vec`,
+// that means something was set or pushed since the builder was created or
+// reset; an optional field counts as set once its setter was called.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+    #[builder(rename = "jobs")]
+    job_count: u32,
+}
+
+fn main() {
+    let mut builder = Command::builder();
+    assert!(!builder.is_executable_set());
+    assert!(!builder.is_args_set());
+    assert!(!builder.is_current_dir_set());
+    assert!(!builder.is_jobs_set());
+
+    builder.executable("cargo".to_owned()).arg("build".to_owned());
+    assert!(builder.is_executable_set());
+    assert!(builder.is_args_set());
+    assert!(!builder.is_current_dir_set());
+
+    builder.current_dir("..".to_owned()).jobs(2);
+    assert!(builder.is_current_dir_set());
+    assert!(builder.is_jobs_set());
+
+    builder.reset();
+    assert!(!builder.is_executable_set());
+    assert!(!builder.is_args_set());
+}
@@ -0,0 +1,43 @@
+// With #[builder(default)] on the struct, build() can no longer fail and
+// returns the struct itself. It starts from the struct's `Default` value and
+// overrides only the fields whose setters were called, so the struct has to
+// implement `Default`. A field-level `default` still wins over the struct's
+// default for that field.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug, Default, PartialEq)]
+#[builder(default)]
+pub struct Server {
+    host: String,
+    port: u16,
+    #[builder(each = "alias")]
+    aliases: Vec<String>,
+    #[builder(default = "8")]
+    workers: u32,
+    timeout: Option<u64>,
+}
+
+fn main() {
+    let server: Server = Server::builder().port(8080).build();
+    assert_eq!(
+        server,
+        Server {
+            host: String::new(),
+            port: 8080,
+            aliases: Vec::new(),
+            workers: 8,
+            timeout: None,
+        }
+    );
+
+    let server = Server::builder()
+        .host("localhost".to_owned())
+        .alias("local".to_owned())
+        .timeout(30)
+        .build();
+    assert_eq!(server.host, "localhost");
+    assert_eq!(server.port, 0);
+    assert_eq!(server.aliases, vec!["local"]);
+    assert_eq!(server.timeout, Some(30));
+}
@@ -0,0 +1,36 @@
+// Lifetime and type parameters can be mixed, including outlives bounds like
+// `T: 'a` written inline or in a where clause. The builder, builder(), and
+// build() all carry them over unchanged.
+
+use derive_builder::Builder;
+use std::fmt::Debug;
+
+#[derive(Builder)]
+pub struct Holder<'a, T: 'a> {
+    item: &'a T,
+}
+
+#[derive(Builder)]
+pub struct Labeled<'a, 'b, T>
+where
+    T: Debug + 'a,
+    'a: 'b,
+{
+    item: &'a T,
+    label: Option<&'b str>,
+}
+
+fn main() {
+    let value = vec![1, 2, 3];
+    let holder = Holder::builder().item(&value).build().unwrap();
+    assert_eq!(holder.item, &[1, 2, 3]);
+
+    let name = String::from("numbers");
+    let labeled = Labeled::builder()
+        .item(&value)
+        .label(&name)
+        .build()
+        .unwrap();
+    assert_eq!(format!("{:?}", labeled.item), "[1, 2, 3]");
+    assert_eq!(labeled.label, Some("numbers"));
+}
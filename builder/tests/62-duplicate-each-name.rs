@@ -0,0 +1,14 @@
+// Two fields can't use the same `each` name, since both one-at-a-time
+// setters would then be the same method. The second use is reported.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Pipeline {
+    #[builder(each = "item")]
+    inputs: Vec<String>,
+    #[builder(each = "item")]
+    outputs: Vec<String>,
+}
+
+fn main() {}
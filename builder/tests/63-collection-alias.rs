@@ -0,0 +1,48 @@
+// A collection behind a type alias isn't recognized from its name, so it gets
+// no `each` support by default. #[builder(collection = "...")] says which
+// collection the alias stands for: "vec", "vec_deque" or "hash_set". The
+// field then behaves exactly as if it had been written out, with the element
+// type taken from the collection itself.
+
+use derive_builder::Builder;
+use std::collections::{HashSet, VecDeque};
+
+type Args = Vec<String>;
+type Queue = VecDeque<u32>;
+type Tags = HashSet<&'static str>;
+
+#[derive(Builder)]
+pub struct Command {
+    #[builder(each = "arg", collection = "vec")]
+    args: Args,
+    #[builder(each = "job", collection = "vec_deque")]
+    jobs: Queue,
+    #[builder(each = "tag", collection = "hash_set")]
+    tags: Tags,
+    #[builder(collection = "vec")]
+    env: Args,
+}
+
+fn main() {
+    let command = Command::builder()
+        .arg("build".to_owned())
+        .arg("--release".to_owned())
+        .job(1)
+        .job(2)
+        .tag("ci")
+        .build()
+        .unwrap();
+
+    assert_eq!(command.args, vec!["build", "--release"]);
+    assert_eq!(command.jobs, [1, 2]);
+    assert!(command.tags.contains("ci"));
+    assert!(command.env.is_empty());
+
+    let command = Command::builder()
+        .args(vec!["test".to_owned()])
+        .env(vec!["RUST_LOG=debug".to_owned()])
+        .build()
+        .unwrap();
+    assert_eq!(command.args, vec!["test"]);
+    assert_eq!(command.env, vec!["RUST_LOG=debug"]);
+}
@@ -0,0 +1,45 @@
+// A missing-field error starts with the struct being built, so that errors
+// from several builders that end up in the same place can be told apart.
+// Each field is quoted by name, and all of them are listed if more than one
+// is missing. The same goes for the boxed error.
+
+use derive_builder::Builder;
+use std::error::Error;
+
+#[derive(Builder, Debug)]
+pub struct OnceCommand {
+    executable: String,
+    current_dir: String,
+}
+
+#[derive(Builder, Debug)]
+#[builder(error = "boxed")]
+pub struct Server {
+    host: String,
+    port: u16,
+}
+
+fn main() {
+    let mut errors: Vec<Box<dyn Error>> = Vec::new();
+    errors.push(
+        OnceCommand::builder()
+            .current_dir("..".to_owned())
+            .build()
+            .unwrap_err()
+            .into(),
+    );
+    errors.push(OnceCommand::builder().build().unwrap_err().into());
+    errors.push(Server::builder().port(80).build().unwrap_err());
+    errors.push(Server::builder().build().unwrap_err());
+
+    let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        messages,
+        [
+            "OnceCommand::build: field `executable` not initialized",
+            "OnceCommand::build: fields `executable`, `current_dir` not initialized",
+            "Server::build: field `host` not initialized",
+            "Server::build: fields `host`, `port` not initialized",
+        ]
+    );
+}
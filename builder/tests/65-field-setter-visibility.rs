@@ -0,0 +1,31 @@
+// Each setter is as visible as the field it sets: a `pub` field gets a `pub`
+// setter, and a private field gets a setter that is private to the module
+// the struct is defined in. build() stays public, so code elsewhere can still
+// build the struct as long as the private fields are optional or were set
+// inside the module. A struct-level #[builder(vis = "...")] gives every setter
+// the same visibility instead.
+
+mod config {
+    use derive_builder::Builder;
+
+    #[derive(Builder)]
+    pub struct Command {
+        pub executable: String,
+        pub(crate) current_dir: Option<String>,
+        secret: Option<String>,
+    }
+
+    pub fn with_secret() -> CommandBuilder {
+        let mut builder = Command::builder();
+        builder.secret("hunter2".to_owned());
+        builder
+    }
+}
+
+fn main() {
+    let mut builder = config::with_secret();
+    builder.executable("cargo".to_owned()).current_dir("..".to_owned());
+    let _command = builder.build().unwrap();
+
+    config::Command::builder().secret("guess".to_owned());
+}
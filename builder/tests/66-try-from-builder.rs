@@ -0,0 +1,40 @@
+// The struct implements `TryFrom` its builder, converting exactly like
+// build() and failing with the same error. That lets a builder be passed to
+// code written against the standard conversion traits.
+
+use derive_builder::Builder;
+use std::convert::TryFrom;
+
+#[derive(Builder, Debug)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+}
+
+#[derive(Builder, Debug)]
+#[builder(error = "boxed", build_by = "value")]
+pub struct Server {
+    port: u16,
+}
+
+fn parse<T: TryFrom<B>, B>(builder: B) -> Result<T, T::Error> {
+    T::try_from(builder)
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut builder = Command::builder();
+    builder.executable("cargo".to_owned()).arg("build".to_owned());
+    let command = Command::try_from(builder)?;
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build"]);
+
+    let err: CommandBuilderError = parse::<Command, _>(Command::builder()).unwrap_err();
+    assert!(matches!(err, CommandBuilderError::MissingExecutable));
+
+    let mut builder = Server::builder();
+    builder.port(8080);
+    let server: Server = builder.try_into()?;
+    assert_eq!(server.port, 8080);
+    Ok(())
+}
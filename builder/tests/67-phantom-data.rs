@@ -0,0 +1,28 @@
+// A `PhantomData` field has nothing to set, so it gets no setter and doesn't
+// count as missing. build() fills it in, the same as for a skipped field.
+
+use derive_builder::Builder;
+use std::marker::PhantomData;
+
+pub struct Meters;
+
+#[derive(Builder, Debug)]
+pub struct Distance<Unit> {
+    value: f64,
+    unit: PhantomData<Unit>,
+}
+
+#[derive(Builder)]
+pub struct Handle<'a, T> {
+    id: u32,
+    _marker: std::marker::PhantomData<&'a T>,
+}
+
+fn main() {
+    let distance: Distance<Meters> = Distance::builder().value(1.5).build().unwrap();
+    assert_eq!(distance.value, 1.5);
+    let PhantomData = distance.unit;
+
+    let handle = Handle::<'static, String>::builder().id(7).build().unwrap();
+    assert_eq!(handle.id, 7);
+}
@@ -0,0 +1,29 @@
+// An Option<Vec<T>> field with `each` tells "never touched" apart from
+// "touched": build() leaves it None unless an item was pushed or the
+// all-at-once setter was called, even with an empty Vec. After reset() it is
+// untouched again.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    #[builder(each = "arg")]
+    args: Option<Vec<String>>,
+}
+
+fn main() {
+    let mut builder = Command::builder();
+    assert_eq!(builder.build().unwrap().args, None);
+
+    builder.arg("build".to_owned());
+    assert_eq!(builder.build().unwrap().args, Some(vec!["build".to_owned()]));
+
+    // build() took the pushed items, so the field is untouched again.
+    assert_eq!(builder.build().unwrap().args, None);
+
+    builder.args(Vec::new());
+    assert_eq!(builder.build().unwrap().args, Some(Vec::new()));
+
+    builder.arg("test".to_owned()).reset();
+    assert_eq!(builder.build().unwrap().args, None);
+}
@@ -0,0 +1,13 @@
+// The `each` name has to be given as a string literal. Anything else is
+// reported at the value itself, not at the whole attribute.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = arg)]
+    args: Vec<String>,
+}
+
+fn main() {}
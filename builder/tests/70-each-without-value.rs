@@ -0,0 +1,13 @@
+// `each` needs a value. Leaving it out is reported right after the `each`
+// key, where the `= "..."` is missing.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each, into)]
+    args: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+// Struct-level options that can't be used together are reported at the
+// option that completes the conflict, rather than at the whole attribute.
+// A typestate build() can't fail, so it has no error to report a rejected
+// validation with.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(typestate, must_use, validate = "check")]
+pub struct Command {
+    executable: String,
+}
+
+fn check(_: &Command) -> Result<(), String> {
+    Ok(())
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+// A generic parameter with a default, like `T = String`, keeps its default on
+// the builder struct, so `StoreBuilder` on its own means `StoreBuilder<String>`
+// just as `Store` means `Store<String>`. The generated impls work for any `T`.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Store<T = String> {
+    value: T,
+    #[builder(each = "tag")]
+    tags: Vec<String>,
+}
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Typed<K, V = u32> {
+    key: K,
+    value: V,
+}
+
+fn main() {
+    let mut builder: StoreBuilder = Store::builder();
+    let store: Store = builder.value("text".to_owned()).build().unwrap();
+    assert_eq!(store.value, "text");
+
+    let store = Store::<u8>::builder().value(7).tag("small".to_owned()).build().unwrap();
+    assert_eq!(store.value, 7);
+    assert_eq!(store.tags, vec!["small"]);
+
+    let typed: Typed<&str> = Typed::builder().key("answer").value(42).build();
+    assert_eq!((typed.key, typed.value), ("answer", 42));
+}
@@ -0,0 +1,35 @@
+// With #[builder(unchecked)] the builder also gets an `unsafe fn
+// build_unchecked()`, which fills in the struct without checking that the
+// required fields were set, and so returns it directly rather than in a
+// Result. It is up to the caller to have set every one of them; optional and
+// repeated fields are filled in the same way as by build().
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(unchecked)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+    #[builder(unwrap)]
+    env: Box<Vec<String>>,
+}
+
+fn main() {
+    let mut builder = Command::builder();
+    builder
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .env(vec![]);
+    // SAFETY: `executable` and `env` are set above.
+    let command = unsafe { builder.build_unchecked() };
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build"]);
+    assert_eq!(command.current_dir, None);
+    assert!(command.env.is_empty());
+
+    // The checked build() is still there.
+    assert!(Command::builder().build().is_err());
+}
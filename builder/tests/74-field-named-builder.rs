@@ -0,0 +1,33 @@
+// A field may be called `builder`: its setter lives on the builder type, so it
+// doesn't get in the way of the struct's own builder() function. Nor do the
+// names of other fields or `each` setters clash with anything the generated
+// code uses internally.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+pub struct Job {
+    builder: String,
+    #[builder(each = "x")]
+    xs: Vec<i32>,
+    #[builder(each = "v")]
+    values: Vec<String>,
+    missing: Option<u32>,
+}
+
+fn main() {
+    let job = Job::builder()
+        .builder("release".to_owned())
+        .x(1)
+        .x(2)
+        .v("a".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(job.builder, "release");
+    assert_eq!(job.xs, vec![1, 2]);
+    assert_eq!(job.values, vec!["a"]);
+    assert_eq!(job.missing, None);
+
+    let err = Job::builder().build().unwrap_err();
+    assert_eq!(err.to_string(), "Job::build: field `builder` not initialized");
+}
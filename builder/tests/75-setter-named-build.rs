@@ -0,0 +1,17 @@
+// A setter can't be named after one of the builder's own methods, `build` or
+// `reset`, whether it comes from the field name or from `each`. Rather than
+// the compiler's report of a duplicate definition somewhere in the generated
+// code, the error points at the offending name.
+//
+// Renaming the setter with #[builder(rename = "...")] keeps the field name.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Pipeline {
+    build: String,
+    #[builder(each = "reset")]
+    steps: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,29 @@
+// The function that creates the builder is called builder() unless the
+// struct-level #[builder(constructor = "...")] names it differently, which
+// leaves `builder` free for the struct's own use. As with `name`, the value
+// has to be a valid identifier.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(constructor = "new_builder")]
+pub struct Package {
+    name: String,
+    builder: String,
+}
+
+impl Package {
+    pub fn builder(&self) -> &str {
+        &self.builder
+    }
+}
+
+fn main() {
+    let package = Package::new_builder()
+        .name("crate".to_owned())
+        .builder("cargo".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(package.name, "crate");
+    assert_eq!(package.builder(), "cargo");
+}
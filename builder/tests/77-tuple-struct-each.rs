@@ -0,0 +1,35 @@
+// An `each` setter works on a positional field too. The builder still stores
+// the field by its index, while the setter takes the name given by `each`.
+// Since that name differs from the positional `field_N`, the all-at-once
+// setter is generated as well.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+pub struct Paths(#[builder(each = "path")] Vec<String>);
+
+#[derive(Builder, Debug)]
+pub struct Route(String, #[builder(each = "stop")] Vec<String>);
+
+fn main() {
+    let paths = Paths::builder()
+        .path("/usr/bin".to_owned())
+        .path("/bin".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(paths.0, vec!["/usr/bin", "/bin"]);
+
+    let paths = Paths::builder().field_0(vec!["/sbin".to_owned()]).build().unwrap();
+    assert_eq!(paths.0, vec!["/sbin"]);
+
+    assert!(Paths::builder().build().unwrap().0.is_empty());
+
+    let route = Route::builder()
+        .field_0("north".to_owned())
+        .stop("a".to_owned())
+        .stop("b".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(route.0, "north");
+    assert_eq!(route.1, vec!["a", "b"]);
+}
@@ -0,0 +1,48 @@
+// build() takes the values out of a builder borrowed by `&mut self`, so a
+// second call right after a successful one has nothing left to build from.
+// Rather than listing every required field as not initialized, it reports
+// that the builder was already consumed, through a `Consumed` variant of the
+// generated error enum or as a boxed error.
+//
+// Once anything is set again, build() goes back to reporting the missing
+// fields, and reset() makes the builder good as new.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+pub struct Command {
+    executable: String,
+    args: Vec<String>,
+    env: Vec<String>,
+}
+
+#[derive(Builder, Debug)]
+#[builder(error = "boxed")]
+pub struct Job {
+    name: String,
+}
+
+fn main() {
+    let mut builder = Command::builder();
+    builder.executable("cargo".to_owned()).args(vec![]).env(vec![]);
+    assert!(builder.build().is_ok());
+
+    let err = builder.build().unwrap_err();
+    assert!(matches!(err, CommandBuilderError::Consumed));
+    assert_eq!(err.to_string(), "Command::build: builder already consumed");
+
+    builder.args(vec!["build".to_owned()]);
+    let err = builder.build().unwrap_err();
+    assert_eq!(err.to_string(), "Command::build: field `executable` not initialized");
+
+    builder.executable("rustc".to_owned());
+    assert_eq!(builder.build().unwrap().executable, "rustc");
+    let err = builder.reset().build().unwrap_err();
+    assert_eq!(err.to_string(), "Command::build: field `executable` not initialized");
+
+    let mut builder = Job::builder();
+    builder.name("deploy".to_owned());
+    assert_eq!(builder.build().unwrap().name, "deploy");
+    let err = builder.build().unwrap_err();
+    assert_eq!(err.to_string(), "Job::build: builder already consumed");
+}
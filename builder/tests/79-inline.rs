@@ -0,0 +1,27 @@
+// A struct-level #[builder(inline)] puts #[inline] on builder() and on every
+// setter, whose bodies are trivial enough to be worth inlining across crates.
+// The generated code behaves exactly as it does without it.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(inline)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(into)]
+    current_dir: Option<String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .current_dir("..")
+        .build()
+        .unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build"]);
+    assert_eq!(command.current_dir.as_deref(), Some(".."));
+}
@@ -0,0 +1,14 @@
+// Options like `each` only mean something on a field. When one ends up on the
+// struct instead, the error says where it belongs, rather than calling it an
+// unrecognized option.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(each = "arg")]
+pub struct Command {
+    executable: String,
+    args: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+// A `Cow<'a, str>` field with #[builder(into)] takes anything that converts
+// into the Cow, so the caller can pass a borrowed `&'a str` or an owned
+// String alike. The lifetime is threaded through the builder like any other
+// generic parameter, and optional Cow fields work the same way.
+
+use derive_builder::Builder;
+use std::borrow::Cow;
+
+#[derive(Builder, Debug)]
+pub struct Greeting<'a> {
+    #[builder(into)]
+    name: Cow<'a, str>,
+    #[builder(into)]
+    title: Option<Cow<'a, str>>,
+}
+
+fn main() {
+    let name = "Ferris";
+    let greeting = Greeting::builder().name(name).build().unwrap();
+    assert!(matches!(greeting.name, Cow::Borrowed("Ferris")));
+    assert_eq!(greeting.title, None);
+
+    let greeting = Greeting::builder()
+        .name(format!("{}-{}", name, 2))
+        .title("Dr")
+        .build()
+        .unwrap();
+    assert!(matches!(greeting.name, Cow::Owned(ref owned) if owned == "Ferris-2"));
+    assert_eq!(greeting.title.as_deref(), Some("Dr"));
+}
@@ -0,0 +1,45 @@
+// #[builder(default_fn = "path::to::function")] is the same as a `default`
+// expression calling the named function with no arguments: build() calls it if
+// the field was never set, and only then. The function returns the field's
+// own type, so for an Option field it returns the Option; nothing is wrapped
+// on top of what it returns.
+
+use derive_builder::Builder;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static NEXT_ID: AtomicU32 = AtomicU32::new(1);
+
+mod ids {
+    use super::*;
+
+    pub fn next() -> u32 {
+        NEXT_ID.fetch_add(1, Ordering::Relaxed)
+    }
+
+    pub fn next_label() -> Option<String> {
+        Some(format!("job-{}", NEXT_ID.load(Ordering::Relaxed)))
+    }
+}
+
+#[derive(Builder, Debug)]
+pub struct Job {
+    #[builder(default_fn = "ids::next")]
+    id: u32,
+    #[builder(default_fn = "crate::ids::next_label")]
+    label: Option<String>,
+}
+
+fn main() {
+    let job = Job::builder().build().unwrap();
+    assert_eq!(job.id, 1);
+    assert_eq!(job.label.as_deref(), Some("job-2"));
+
+    let job = Job::builder().build().unwrap();
+    assert_eq!(job.id, 2);
+
+    // A set field doesn't call the function.
+    let job = Job::builder().id(10).label("mine".to_owned()).build().unwrap();
+    assert_eq!(job.id, 10);
+    assert_eq!(job.label.as_deref(), Some("mine"));
+    assert_eq!(ids::next(), 3);
+}
@@ -0,0 +1,32 @@
+// Attributes on the struct other than derives and cfgs aren't copied to the
+// builder. A #[repr(C)] or #[repr(align(...))] describes the struct's own
+// layout and stays on the struct alone, where it keeps its effect.
+
+use derive_builder::Builder;
+use std::mem::align_of;
+
+/// A point laid out for C.
+#[derive(Builder, Debug)]
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Builder, Debug)]
+#[repr(C, align(64))]
+pub struct Line {
+    start: u8,
+    end: Option<u8>,
+}
+
+fn main() {
+    let point = Point::builder().x(1).y(2).build().unwrap();
+    assert_eq!((point.x, point.y), (1, 2));
+
+    let line = Line::builder().start(0).build().unwrap();
+    assert_eq!(line.start, 0);
+    assert_eq!(line.end, None);
+    assert_eq!(align_of::<Line>(), 64);
+    assert!(align_of::<LineBuilder>() < 64);
+}
@@ -0,0 +1,64 @@
+// The builder has a with() method that runs a closure on it and returns it for
+// further chaining. That makes it possible to configure some fields only under
+// a condition, which a chain of setters alone can't express.
+//
+// Owned setters consume the builder, so with style = "owned" (and typestate)
+// the closure takes the builder and returns it. In a typestate builder it
+// can only set fields that don't change the builder's type.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+}
+
+#[derive(Builder, Debug)]
+#[builder(style = "owned")]
+pub struct Request {
+    url: String,
+    timeout: Option<u32>,
+}
+
+#[derive(Builder, Debug)]
+#[builder(typestate)]
+pub struct Job {
+    name: String,
+    retries: Option<u32>,
+}
+
+fn command(release: bool) -> Command {
+    Command::builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .with(|builder| {
+            if release {
+                builder.arg("--release".to_owned());
+            }
+        })
+        .build()
+        .unwrap()
+}
+
+fn main() {
+    assert_eq!(command(false).args, vec!["build"]);
+    assert_eq!(command(true).args, vec!["build", "--release"]);
+    assert_eq!(command(true).current_dir, None);
+
+    let slow = true;
+    let request = Request::builder()
+        .url("https://example.com".to_owned())
+        .with(|builder| if slow { builder.timeout(60) } else { builder })
+        .build()
+        .unwrap();
+    assert_eq!(request.timeout, Some(60));
+
+    let job = Job::builder()
+        .with(|builder| builder.retries(3))
+        .name("deploy".to_owned())
+        .build();
+    assert_eq!(job.retries, Some(3));
+}
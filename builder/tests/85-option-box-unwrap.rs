@@ -0,0 +1,38 @@
+// An `Option<Box<T>>` field is an optional field like any other, whose setter
+// takes the `Box<T>`. With #[builder(unwrap)] the setter takes the `T` instead,
+// and build() wraps it, leaving the field `None` if it was never set. The same
+// goes for `Rc` and `Arc` inside an `Option`.
+
+use derive_builder::Builder;
+use std::rc::Rc;
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    verbose: bool,
+}
+
+#[derive(Builder, Debug)]
+pub struct Command {
+    config: Option<Box<Config>>,
+    #[builder(unwrap)]
+    fallback: Option<Box<Config>>,
+    #[builder(unwrap, into)]
+    name: Option<Rc<String>>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .config(Box::new(Config { verbose: true }))
+        .fallback(Config { verbose: false })
+        .name("cargo")
+        .build()
+        .unwrap();
+    assert_eq!(command.config, Some(Box::new(Config { verbose: true })));
+    assert_eq!(command.fallback, Some(Box::new(Config { verbose: false })));
+    assert_eq!(command.name.as_deref().map(String::as_str), Some("cargo"));
+
+    let command = Command::builder().build().unwrap();
+    assert_eq!(command.config, None);
+    assert_eq!(command.fallback, None);
+    assert_eq!(command.name, None);
+}
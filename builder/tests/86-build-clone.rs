@@ -0,0 +1,46 @@
+// With #[builder(clone_build)] the builder also gets build_clone(), which
+// builds from clones of the fields rather than moving them out. The builder is
+// left as it was, so one configured builder can produce any number of
+// structs. Only the fields' types need to be Clone, not the builder.
+//
+// build_clone() returns whatever build() does, errors included.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(clone_build)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+}
+
+#[derive(Builder, Debug)]
+#[builder(clone_build, typestate)]
+pub struct Job {
+    name: String,
+    retries: Option<u32>,
+}
+
+fn main() {
+    let mut builder = Command::builder();
+    builder.executable("cargo".to_owned()).arg("build".to_owned());
+
+    let first = builder.build_clone().unwrap();
+    let second = builder.build_clone().unwrap();
+    assert_eq!(first.executable, "cargo");
+    assert_eq!(second.executable, "cargo");
+    assert_eq!(second.args, vec!["build"]);
+    assert!(builder.is_executable_set());
+
+    builder.arg("--release".to_owned());
+    assert_eq!(builder.build_clone().unwrap().args, vec!["build", "--release"]);
+
+    let err = Command::builder().build_clone().unwrap_err();
+    assert_eq!(err.to_string(), "Command::build: field `executable` not initialized");
+
+    let builder = Job::builder().name("deploy".to_owned()).retries(2);
+    assert_eq!(builder.build_clone().name, "deploy");
+    assert_eq!(builder.build().retries, Some(2));
+}
@@ -0,0 +1,42 @@
+// A field's type may mention `Self`, as in a recursive struct. On the struct
+// `Self` is the struct, but on the builder's fields and setters it would be
+// the builder, so the builder spells out the struct's type in its place. This
+// works through an Option, a Box, a Vec and generic parameters alike.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+pub struct Node<T> {
+    value: T,
+    next: Option<Box<Self>>,
+}
+
+#[derive(Builder, Debug)]
+pub struct Tree {
+    name: String,
+    #[builder(each = "child")]
+    children: Vec<Self>,
+}
+
+fn main() {
+    let tail = Node::builder().value(3).build().unwrap();
+    let middle = Node::builder().value(2).next(Box::new(tail)).build().unwrap();
+    let head = Node::builder().value(1).next(Box::new(middle)).build().unwrap();
+
+    let mut values = vec![];
+    let mut node = Some(&head);
+    while let Some(current) = node {
+        values.push(current.value);
+        node = current.next.as_deref();
+    }
+    assert_eq!(values, vec![1, 2, 3]);
+
+    let leaf = Tree::builder().name("leaf".to_owned()).build().unwrap();
+    let root = Tree::builder()
+        .name("root".to_owned())
+        .child(leaf)
+        .build()
+        .unwrap();
+    assert_eq!(root.children[0].name, "leaf");
+    assert!(root.children[0].children.is_empty());
+}
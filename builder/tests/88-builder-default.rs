@@ -0,0 +1,43 @@
+// The builder implements Default, starting out the same as builder() does
+// with no field set. That also covers generic builders, which get the same
+// bounds as the struct. A builder that derives Default through
+// #[builder(derive(Default))] keeps the derived impl instead.
+
+use derive_builder::Builder;
+use std::fmt::Display;
+
+#[derive(Builder, Debug)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+}
+
+#[derive(Builder, Debug)]
+pub struct Labeled<T>
+where
+    T: Display,
+{
+    value: T,
+}
+
+#[derive(Builder, Debug)]
+#[builder(derive(Default))]
+pub struct Job {
+    name: String,
+}
+
+fn main() {
+    let mut builder = CommandBuilder::default();
+    builder.executable("cargo".to_owned()).arg("build".to_owned());
+    let command = builder.build().unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build"]);
+    assert_eq!(command.current_dir, None);
+
+    let mut builder: LabeledBuilder<u32> = Default::default();
+    assert_eq!(builder.value(7).build().unwrap().value, 7);
+
+    assert!(JobBuilder::default().build().is_err());
+}
@@ -0,0 +1,35 @@
+// A `LinkedList<T>` field gets the same treatment as a `VecDeque<T>`. Its
+// `each` setter appends one element at the end with `push_back`, the
+// all-at-once setter takes a whole `LinkedList<T>`, and a list that was never
+// set is built empty.
+
+use derive_builder::Builder;
+use std::collections::LinkedList;
+
+#[derive(Builder, Debug)]
+pub struct Playlist {
+    #[builder(each = "track")]
+    tracks: LinkedList<String>,
+    #[builder(each = "tag")]
+    tags: std::collections::LinkedList<String>,
+}
+
+fn main() {
+    let playlist = Playlist::builder()
+        .track("intro".to_owned())
+        .track("outro".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(
+        playlist.tracks.iter().collect::<Vec<_>>(),
+        vec!["intro", "outro"]
+    );
+    assert!(playlist.tags.is_empty());
+
+    let playlist = Playlist::builder()
+        .tracks(LinkedList::from(["a".to_owned()]))
+        .track("b".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(playlist.tracks.iter().collect::<Vec<_>>(), vec!["a", "b"]);
+}
@@ -0,0 +1,42 @@
+// An `Option` field is normally optional, and left `None` if never set. With
+// #[builder(required)] it becomes a required field like any other: the setter
+// takes the whole `Option`, so that the caller has to choose explicitly,
+// `None` included, and build() fails if they didn't.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+pub struct Command {
+    executable: String,
+    #[builder(required)]
+    current_dir: Option<String>,
+    #[builder(required, into)]
+    timeout: Option<u32>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .current_dir(None)
+        .timeout(30)
+        .build()
+        .unwrap();
+    assert_eq!(command.current_dir, None);
+    assert_eq!(command.timeout, Some(30));
+
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .current_dir(Some("..".to_owned()))
+        .timeout(None)
+        .build()
+        .unwrap();
+    assert_eq!(command.current_dir.as_deref(), Some(".."));
+
+    let err = Command::builder()
+        .executable("cargo".to_owned())
+        .timeout(None)
+        .build()
+        .unwrap_err();
+    assert!(matches!(err, CommandBuilderError::MissingCurrentDir));
+    assert_eq!(err.to_string(), "Command::build: field `current_dir` not initialized");
+}
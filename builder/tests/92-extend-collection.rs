@@ -0,0 +1,50 @@
+// #[builder(extend)] makes any collection type that implements Default and
+// Extend work with `each`, not just the standard collections the builder
+// knows. The `each` setter extends the collection with one item, of any type
+// the collection can be extended with, and an unset collection is built as
+// its Default. The all-at-once setter takes the whole collection.
+
+use derive_builder::Builder;
+
+#[derive(Default, Debug, PartialEq)]
+pub struct Tags(Vec<String>);
+
+impl Extend<String> for Tags {
+    fn extend<I: IntoIterator<Item = String>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<'a> Extend<&'a str> for Tags {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        self.0.extend(iter.into_iter().map(str::to_owned));
+    }
+}
+
+#[derive(Builder, Debug)]
+pub struct Post {
+    title: String,
+    #[builder(each = "tag", extend)]
+    tags: Tags,
+    #[builder(extend)]
+    other_tags: Tags,
+}
+
+fn main() {
+    let post = Post::builder()
+        .title("Hello".to_owned())
+        .tag("rust")
+        .tag("macros".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(post.tags, Tags(vec!["rust".to_owned(), "macros".to_owned()]));
+    assert_eq!(post.other_tags, Tags::default());
+
+    let post = Post::builder()
+        .title("Again".to_owned())
+        .tags(Tags(vec!["a".to_owned()]))
+        .tag("b")
+        .build()
+        .unwrap();
+    assert_eq!(post.tags, Tags(vec!["a".to_owned(), "b".to_owned()]));
+}
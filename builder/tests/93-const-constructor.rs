@@ -0,0 +1,38 @@
+// With #[builder(const)], builder() is a `const fn`. A builder with nothing
+// set can then be a constant, or the starting point of a static, and copies
+// of it are configured at run time as usual.
+//
+// reset() stays a regular function: clearing a field drops its value, which a
+// const fn can't do for types like String.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(const, derive(Clone))]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+}
+
+#[derive(Builder, Debug)]
+#[builder(const, typestate)]
+pub struct Job {
+    name: String,
+}
+
+const COMMAND: CommandBuilder = Command::builder();
+
+fn main() {
+    let mut builder = COMMAND;
+    builder.executable("cargo".to_owned()).arg("build".to_owned());
+    let command = builder.build().unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build"]);
+
+    assert!(COMMAND.clone().build().is_err());
+
+    const JOB: JobBuilder<JobBuilderUnset> = Job::builder();
+    assert_eq!(JOB.name("deploy".to_owned()).build().name, "deploy");
+}
@@ -0,0 +1,49 @@
+// Every `each` setter returns the builder the same way the all-at-once setters
+// do, so any number of calls chain, and the elements end up in the order they
+// were added. That holds for borrowed and owned setters alike, and when `each`
+// calls are interleaved with other setters.
+
+use derive_builder::Builder;
+use std::collections::VecDeque;
+
+#[derive(Builder, Debug)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(each = "env")]
+    envs: VecDeque<String>,
+}
+
+#[derive(Builder, Debug)]
+#[builder(style = "owned")]
+pub struct Owned {
+    #[builder(each = "arg")]
+    args: Vec<String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .arg("a".to_owned())
+        .env("X=1".to_owned())
+        .arg("b".to_owned())
+        .executable("cargo".to_owned())
+        .arg("c".to_owned())
+        .env("Y=2".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(command.args, vec!["a", "b", "c"]);
+    assert_eq!(command.envs, vec!["X=1", "Y=2"]);
+
+    let mut builder = Command::builder();
+    let same: *const CommandBuilder = builder.arg("a".to_owned()).arg("b".to_owned());
+    assert_eq!(same, &builder as *const _);
+
+    let owned = Owned::builder()
+        .arg("a".to_owned())
+        .arg("b".to_owned())
+        .arg("c".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(owned.args, vec!["a", "b", "c"]);
+}
@@ -0,0 +1,45 @@
+// Each field's `each` name belongs to that field alone, however the fields
+// around it are classified. Plain, optional and collection fields can be
+// mixed in any order, with or without `each`, and every setter still reaches
+// its own field.
+
+use derive_builder::Builder;
+use std::collections::HashMap;
+
+#[derive(Builder, Debug)]
+pub struct Command {
+    current_dir: Option<String>,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    executable: String,
+    features: Vec<String>,
+    #[builder(each = "env")]
+    envs: HashMap<String, String>,
+    timeout: Option<u32>,
+    #[builder(each = "target")]
+    targets: Vec<String>,
+    jobs: u32,
+}
+
+fn main() {
+    let command = Command::builder()
+        .target("x86_64".to_owned())
+        .arg("build".to_owned())
+        .jobs(4)
+        .env("RUST_LOG".to_owned(), "debug".to_owned())
+        .executable("cargo".to_owned())
+        .features(vec!["full".to_owned()])
+        .target("aarch64".to_owned())
+        .timeout(60)
+        .build()
+        .unwrap();
+
+    assert_eq!(command.current_dir, None);
+    assert_eq!(command.args, vec!["build"]);
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.features, vec!["full"]);
+    assert_eq!(command.envs["RUST_LOG"], "debug");
+    assert_eq!(command.timeout, Some(60));
+    assert_eq!(command.targets, vec!["x86_64", "aarch64"]);
+    assert_eq!(command.jobs, 4);
+}
@@ -0,0 +1,87 @@
+// Everything the builder knows about a field stays with that field, whatever
+// mix of kinds and options surrounds it. Two structs with the same fields in
+// opposite orders build the same values, including defaults, skipped fields,
+// renamed setters and `each` setters.
+
+use derive_builder::Builder;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Builder, Debug)]
+pub struct Forward {
+    name: String,
+    #[builder(skip)]
+    cache: Vec<u8>,
+    tag: Option<String>,
+    #[builder(each = "item")]
+    items: Vec<u32>,
+    #[builder(default = "7")]
+    retries: u32,
+    #[builder(each = "label")]
+    labels: HashSet<String>,
+    #[builder(rename = "set_limit")]
+    limit: u64,
+    #[builder(each = "var")]
+    vars: HashMap<String, u32>,
+    #[builder(into)]
+    note: Option<String>,
+}
+
+#[derive(Builder, Debug)]
+pub struct Backward {
+    #[builder(into)]
+    note: Option<String>,
+    #[builder(each = "var")]
+    vars: HashMap<String, u32>,
+    #[builder(rename = "set_limit")]
+    limit: u64,
+    #[builder(each = "label")]
+    labels: HashSet<String>,
+    #[builder(default = "7")]
+    retries: u32,
+    #[builder(each = "item")]
+    items: Vec<u32>,
+    tag: Option<String>,
+    #[builder(skip)]
+    cache: Vec<u8>,
+    name: String,
+}
+
+macro_rules! configure {
+    ($builder:expr) => {
+        $builder
+            .item(1)
+            .name("stress".to_owned())
+            .label("a".to_owned())
+            .set_limit(99)
+            .item(2)
+            .var("x".to_owned(), 3)
+            .note("hi")
+            .build()
+            .unwrap()
+    };
+}
+
+fn main() {
+    let forward = configure!(Forward::builder());
+    let backward = configure!(Backward::builder());
+
+    assert_eq!(forward.name, backward.name);
+    assert_eq!(forward.cache, backward.cache);
+    assert!(forward.cache.is_empty());
+    assert_eq!(forward.tag, backward.tag);
+    assert_eq!(forward.tag, None);
+    assert_eq!(forward.items, backward.items);
+    assert_eq!(forward.items, vec![1, 2]);
+    assert_eq!(forward.retries, backward.retries);
+    assert_eq!(forward.retries, 7);
+    assert_eq!(forward.labels, backward.labels);
+    assert_eq!(forward.limit, backward.limit);
+    assert_eq!(forward.limit, 99);
+    assert_eq!(forward.vars, backward.vars);
+    assert_eq!(forward.vars["x"], 3);
+    assert_eq!(forward.note, backward.note);
+    assert_eq!(forward.note.as_deref(), Some("hi"));
+
+    let err = Backward::builder().name("x".to_owned()).build().unwrap_err();
+    assert_eq!(err.to_string(), "Backward::build: field `set_limit` not initialized");
+}
@@ -0,0 +1,29 @@
+// A #[non_exhaustive] struct can only be constructed with a struct literal in
+// its own crate. The derive always expands next to the struct, so build()
+// lives in that crate too and is free to construct it; other crates still get
+// their values through the builder, so there is no limitation to report.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[non_exhaustive]
+pub struct Command {
+    pub executable: String,
+    pub current_dir: Option<String>,
+}
+
+#[derive(Builder, Debug)]
+#[non_exhaustive]
+pub struct Point(pub i32, pub i32);
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.current_dir, None);
+
+    let point = Point::builder().field_0(1).field_1(2).build().unwrap();
+    assert_eq!((point.0, point.1), (1, 2));
+}
@@ -0,0 +1,58 @@
+// With #[builder(clearable)] every field also gets a clear_...() method, named
+// like its setter, that puts it back the way builder() left it. build() then
+// treats the field as never set: a required field is reported missing, an
+// optional one is None, a collection is empty and a default applies again.
+//
+// In a typestate builder, clearing a required field marks it as unset in the
+// builder's type, so build() isn't available until it is set again.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(clearable)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+    #[builder(default = "4", rename = "jobs")]
+    job_count: u32,
+}
+
+#[derive(Builder, Debug)]
+#[builder(clearable, typestate)]
+pub struct Job {
+    name: String,
+    retries: Option<u32>,
+}
+
+fn main() {
+    let mut builder = Command::builder();
+    builder
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .current_dir("..".to_owned())
+        .jobs(16);
+    builder.clear_args().clear_current_dir().clear_jobs();
+    let command = builder.build().unwrap();
+    assert!(command.args.is_empty());
+    assert_eq!(command.current_dir, None);
+    assert_eq!(command.job_count, 4);
+
+    let err = Command::builder()
+        .executable("cargo".to_owned())
+        .clear_executable()
+        .build()
+        .unwrap_err();
+    assert_eq!(err.to_string(), "Command::build: field `executable` not initialized");
+
+    let job = Job::builder()
+        .name("deploy".to_owned())
+        .retries(3)
+        .clear_retries()
+        .clear_name()
+        .name("release".to_owned())
+        .build();
+    assert_eq!(job.name, "release");
+    assert_eq!(job.retries, None);
+}
@@ -0,0 +1,56 @@
+// A field-level #[builder(validate = "path::to::function")] checks each value
+// in the setter, before it is stored, rather than once the whole struct is
+// built. The function takes a reference to the value the setter received,
+// after any `into` conversion, and returns `Result<(), E>` for any E that
+// converts into `Box<dyn Error>`. The setter then returns a Result: a rejected
+// value is returned as the error and leaves the field as it was.
+
+use derive_builder::Builder;
+
+fn nonzero_port(port: &u16) -> Result<(), String> {
+    if *port == 0 {
+        return Err("port must not be zero".to_owned());
+    }
+    Ok(())
+}
+
+fn not_empty(host: &String) -> Result<(), &'static str> {
+    if host.is_empty() {
+        return Err("host must not be empty");
+    }
+    Ok(())
+}
+
+#[derive(Builder, Debug)]
+pub struct Server {
+    #[builder(validate = "not_empty", into)]
+    host: String,
+    #[builder(validate = "nonzero_port")]
+    port: u16,
+    #[builder(validate = "nonzero_port")]
+    admin_port: Option<u16>,
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let server = Server::builder()
+        .host("localhost")?
+        .port(8080)?
+        .build()
+        .unwrap();
+    assert_eq!(server.port, 8080);
+    assert_eq!(server.admin_port, None);
+
+    let mut builder = Server::builder();
+    let err = builder.port(0).err().unwrap();
+    assert_eq!(err.to_string(), "port must not be zero");
+    assert!(!builder.is_port_set());
+
+    let err = builder.host("").err().unwrap();
+    assert_eq!(err.to_string(), "host must not be empty");
+    assert!(builder.admin_port(0).is_err());
+
+    builder.host("example.com")?.port(1)?.admin_port(2)?;
+    let server = builder.build().unwrap();
+    assert_eq!(server.admin_port, Some(2));
+    Ok(())
+}
@@ -10,4 +10,114 @@ fn tests() {
     t.pass("tests/07-repeated-field.rs");
     t.compile_fail("tests/08-unrecognized-attribute.rs");
     t.pass("tests/09-redefined-prelude-types.rs");
+    t.pass("tests/10-generic-struct.rs");
+    t.pass("tests/11-where-clause.rs");
+    t.pass("tests/12-lifetime.rs");
+    t.pass("tests/13-tuple-struct.rs");
+    t.compile_fail("tests/14-unsupported-shape.rs");
+    t.pass("tests/15-skip-field.rs");
+    t.pass("tests/16-into-setter.rs");
+    t.pass("tests/17-default-value.rs");
+    t.pass("tests/18-custom-builder-name.rs");
+    t.compile_fail("tests/19-invalid-builder-name.rs");
+    t.pass("tests/20-typed-error.rs");
+    t.pass("tests/21-option-vec-each.rs");
+    t.pass("tests/22-qualified-paths.rs");
+    t.pass("tests/23-doc-comment-each.rs");
+    t.pass("tests/24-combined-options.rs");
+    t.compile_fail("tests/25-duplicate-option.rs");
+    t.pass("tests/26-setter-docs.rs");
+    t.pass("tests/27-derive-clone.rs");
+    t.pass("tests/28-build-by-value.rs");
+    t.pass("tests/29-hash-map-each.rs");
+    t.pass("tests/30-hash-set-each.rs");
+    t.pass("tests/31-each-and-all-at-once.rs");
+    t.compile_fail("tests/32-each-on-non-collection.rs");
+    t.pass("tests/33-rename-setter.rs");
+    t.pass("tests/34-derive-debug.rs");
+    t.pass("tests/35-all-missing-fields.rs");
+    t.pass("tests/36-nested-builder.rs");
+    t.compile_fail("tests/37-builder-visibility.rs");
+    t.pass("tests/38-attribute-order.rs");
+    t.pass("tests/39-unwrap-wrapper.rs");
+    t.pass("tests/40-reset.rs");
+    t.pass("tests/41-const-generics.rs");
+    t.pass("tests/42-each-into.rs");
+    t.pass("tests/43-owned-style.rs");
+    t.pass("tests/44-rename-error.rs");
+    t.pass("tests/45-builder-across-modules.rs");
+    t.pass("tests/46-public-builder.rs");
+    t.pass("tests/47-vec-deque-each.rs");
+    t.compile_fail("tests/48-invalid-each-name.rs");
+    t.pass("tests/49-validate.rs");
+    t.pass("tests/50-try-into-setter.rs");
+    t.pass("tests/51-raw-identifiers.rs");
+    t.pass("tests/52-empty-struct.rs");
+    t.compile_fail("tests/53-must-use.rs");
+    t.pass("tests/54-btree-map-each.rs");
+    t.pass("tests/55-typestate.rs");
+    t.compile_fail("tests/56-typestate-missing-field.rs");
+    t.pass("tests/57-cfg-fields.rs");
+    t.pass("tests/58-nested-option-inner.rs");
+    t.pass("tests/59-is-set.rs");
+    t.pass("tests/60-struct-default.rs");
+    t.pass("tests/61-lifetime-and-type-bound.rs");
+    t.compile_fail("tests/62-duplicate-each-name.rs");
+    t.pass("tests/63-collection-alias.rs");
+    t.pass("tests/64-struct-name-in-error.rs");
+    t.compile_fail("tests/65-field-setter-visibility.rs");
+    t.pass("tests/66-try-from-builder.rs");
+    t.pass("tests/67-phantom-data.rs");
+    t.pass("tests/68-option-vec-untouched.rs");
+    t.compile_fail("tests/69-each-not-a-string.rs");
+    t.compile_fail("tests/70-each-without-value.rs");
+    t.compile_fail("tests/71-conflicting-struct-options.rs");
+    t.pass("tests/72-default-type-parameter.rs");
+    t.pass("tests/73-build-unchecked.rs");
+    t.pass("tests/74-field-named-builder.rs");
+    t.compile_fail("tests/75-setter-named-build.rs");
+    t.pass("tests/76-constructor-name.rs");
+    t.pass("tests/77-tuple-struct-each.rs");
+    t.pass("tests/78-build-twice.rs");
+    t.pass("tests/79-inline.rs");
+    t.compile_fail("tests/80-each-on-struct.rs");
+    t.pass("tests/81-cow-into.rs");
+    t.pass("tests/82-default-fn.rs");
+    t.pass("tests/83-repr-struct.rs");
+    t.pass("tests/84-with.rs");
+    t.pass("tests/85-option-box-unwrap.rs");
+    t.pass("tests/86-build-clone.rs");
+    t.pass("tests/87-self-in-field-type.rs");
+    t.pass("tests/88-builder-default.rs");
+    t.pass("tests/89-linked-list-each.rs");
+    t.pass("tests/91-required-option.rs");
+    t.pass("tests/92-extend-collection.rs");
+    t.pass("tests/93-const-constructor.rs");
+    t.pass("tests/94-each-chaining.rs");
+    t.pass("tests/95-mixed-each-fields.rs");
+    t.pass("tests/96-field-order-stress.rs");
+    t.pass("tests/97-non-exhaustive.rs");
+    t.pass("tests/98-clear-setters.rs");
+    t.pass("tests/99-field-validate.rs");
+    t.pass("tests/100-set-once.rs");
+    t.pass("tests/101-serde-attributes.rs");
+    t.pass("tests/102-extend-setters.rs");
+    t.pass("tests/103-nested-vec-each.rs");
+    t.pass("tests/104-opt-setter.rs");
+    t.pass("tests/105-bare-attribute.rs");
+    t.pass("tests/106-field-lints.rs");
+    t.pass("tests/107-build-boxed.rs");
+    t.pass("tests/108-build-name.rs");
+    t.pass("tests/109-associated-types.rs");
+    t.pass("tests/110-merge.rs");
+    t.pass("tests/111-path-into.rs");
+    t.compile_fail("tests/112-union.rs");
+    t.pass("tests/113-each-with-default.rs");
+    t.pass("tests/114-struct-cfg.rs");
+    t.pass("tests/115-empty-tuple-typestate.rs");
+    t.pass("tests/116-hidden-fields.rs");
+    t.compile_fail("tests/117-generated-method-collision.rs");
+    t.pass("tests/118-struct-derive-default.rs");
+    t.compile_fail("tests/119-error-variant-collision.rs");
+    t.pass("tests/120-typestate-similar-fields.rs");
 }